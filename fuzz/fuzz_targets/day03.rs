@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc::Params;
+use libfuzzer_sys::fuzz_target;
+
+// Inputs the parser accepts must never make the solver panic
+fuzz_target!(|input: &str| {
+    if let Ok(solution) = aoc::day(3).unwrap().parse(input, &Params::new()) {
+        let _ = solution.part1();
+        let _ = solution.part2();
+    }
+});
@@ -9,4 +9,4 @@ else
   day=$(date +%d)
 fi
 
-cat "${day}.input" | cargo run --bin "day${day}" $@
+cargo run --bin aoc -- run "${day}" $@
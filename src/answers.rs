@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Answer, ParseError, Source};
+
+/// The conventional name of the known-answers file, next to the inputs.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Accepted answers for each day, as recorded in the answers file. Each line
+/// holds a day number followed by the part 1 and part 2 answers, where `?`
+/// marks an answer that is not known yet. Blank lines and `#` comments are
+/// ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+/// The outcome of checking an answer against the known answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let mut days = BTreeMap::new();
+        for line in input.lines() {
+            let line = line.split('#').next().unwrap().trim_end();
+            if line.trim().is_empty() {
+                continue;
+            }
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let [day, part1, part2] = words[..] else {
+                return Err(src.error(line, "expected a day followed by two answers"));
+            };
+            let known = |s: &str| match s {
+                "?" => None,
+                _ => Some(s.to_string()),
+            };
+            if days.insert(src.num::<u8>(day)?, [known(part1), known(part2)]).is_some() {
+                return Err(src.error(day, "duplicate day"));
+            }
+        }
+        Ok(Self { days })
+    }
+
+    /// Load answers from `path`. A missing file means no answers are known.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        assert!(part == 1 || part == 2);
+        self.days.get(&day).and_then(|parts| parts[part as usize - 1].as_deref())
+    }
+
+    pub fn verify(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+        }
+    }
+}
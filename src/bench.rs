@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::{Day, Params, ParseError, SolveError, Source};
+
+/// The default baseline file, relative to the working directory.
+pub const BASELINE_FILE: &str = "bench.txt";
+
+/// The separately timed stages of solving a day.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Self; 3] = [Self::Parse, Self::Part1, Self::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part1",
+            Self::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|stage| stage.name() == name)
+    }
+}
+
+/// Summary of repeated timings of one stage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+        Self { min: samples[0], median: samples[samples.len() / 2], max: samples[samples.len() - 1] }
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let ret = black_box(f());
+    (ret, start.elapsed())
+}
+
+/// Time parsing and each part of `day` over `iterations` runs.
+pub fn bench_day(day: &Day, input: &str, params: &Params, iterations: usize) -> Result<[Stats; 3], Box<dyn Error>> {
+    assert!(iterations > 0);
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solution = None;
+    for _ in 0..iterations {
+        let (parsed, elapsed) = time(|| day.parse(input, params));
+        parse_samples.push(elapsed);
+        solution = Some(parsed?);
+    }
+    let solution = solution.unwrap();
+    let part_stats = |part| -> Result<Stats, SolveError> {
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let (answer, elapsed) = time(|| solution.part(part));
+            answer?;
+            samples.push(elapsed);
+        }
+        Ok(Stats::from_samples(samples))
+    };
+    Ok([Stats::from_samples(parse_samples), part_stats(1)?, part_stats(2)?])
+}
+
+/// Median timings per day and stage, saved between runs to spot regressions.
+/// Each line of the file holds a day, a stage name and a median in nanoseconds.
+#[derive(Clone, Debug, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let mut medians = BTreeMap::new();
+        for line in input.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#')) {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let [day, stage, nanos] = words[..] else {
+                return Err(src.error(line, "expected a day, a stage and a duration in ns"));
+            };
+            let stage = Stage::from_name(stage).ok_or_else(|| src.error(stage, "unknown stage"))?;
+            medians.insert((src.num(day)?, stage), Duration::from_nanos(src.num(nanos)?));
+        }
+        Ok(Self { medians })
+    }
+
+    /// Load a baseline from `path`. A missing file gives an empty baseline.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+
+    pub fn set(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# day stage median_ns")?;
+        for ((day, stage), median) in self.medians.iter() {
+            writeln!(f, "{:02} {} {}", day, stage.name(), median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// Format a duration with a unit suited to its magnitude.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    match nanos {
+        0..1_000 => format!("{}ns", nanos),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
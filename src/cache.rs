@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Answer, Day, Params, ParseError, Source};
+
+/// The default cache file, relative to the working directory.
+pub const CACHE_FILE: &str = ".aoc-cache";
+
+/// A checksum of an input (64-bit FNV-1a), to spot identical inputs.
+pub fn checksum(input: &str) -> u64 {
+    fnv(0xcbf29ce484222325, input.as_bytes())
+}
+
+fn fnv(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Answers solved before, saved between runs so that unchanged days need not
+/// be solved again. Each line of the file holds a day, a part, the day's
+/// solution version, a checksum of the input and parameters, and the answer
+/// after a tag for its kind: `n` for a number or `t` for text.
+#[derive(Clone, Debug, Default)]
+pub struct Cache {
+    answers: BTreeMap<(u8, u8, String, u64), Answer>,
+    changed: bool,
+}
+
+impl Cache {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let mut answers = BTreeMap::new();
+        for line in input.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#')) {
+            let words: Vec<&str> = line.splitn(6, ' ').collect();
+            let [day, part, version, key, kind, answer] = words[..] else {
+                return Err(src.error(line, "expected a day, a part, a version, a checksum, a kind and an answer"));
+            };
+            let key = u64::from_str_radix(key, 16).map_err(|_| src.error(key, "expected a hex checksum"))?;
+            let answer = match kind {
+                "n" => Answer::Num(src.num(answer)?),
+                "t" => Answer::Text(answer.to_string()),
+                _ => return Err(src.error(kind, "expected n for a number or t for text")),
+            };
+            answers.insert((src.num(day)?, src.num(part)?, version.to_string(), key), answer);
+        }
+        Ok(Self { answers, changed: false })
+    }
+
+    /// Load the cache from `path`. A missing file gives an empty cache.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Save the cache to `path`, if anything was added since it was loaded.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        match self.changed {
+            true => fs::write(path, self.to_string()),
+            false => Ok(()),
+        }
+    }
+
+    /// What identifies the answers for `input`: the parameters can change
+    /// them as much as the input itself.
+    pub fn key(input: &str, params: &Params) -> u64 {
+        params.iter().fold(checksum(input), |hash, (name, value)| {
+            fnv(hash, format!("\n{}={}", name, value).as_bytes())
+        })
+    }
+
+    pub fn get(&self, day: &Day, part: u8, key: u64) -> Option<&Answer> {
+        self.answers.get(&(day.num, part, day.version().to_string(), key))
+    }
+
+    /// Remember an answer, forgetting any from older versions of the day.
+    pub fn insert(&mut self, day: &Day, part: u8, key: u64, answer: Answer) {
+        self.answers.retain(|(num, _, version, _), _| *num != day.num || version == day.version());
+        self.answers.insert((day.num, part, day.version().to_string(), key), answer);
+        self.changed = true;
+    }
+}
+
+impl fmt::Display for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# day part version checksum kind answer")?;
+        for ((day, part, version, key), answer) in self.answers.iter() {
+            let kind = match answer {
+                Answer::Num(_) => 'n',
+                Answer::Text(_) => 't',
+            };
+            writeln!(f, "{:02} {} {} {:016x} {} {}", day, part, version, key, kind, answer)?;
+        }
+        Ok(())
+    }
+}
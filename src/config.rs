@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Params, ParseError, Source, DAYS};
+
+/// The default config file, relative to the working directory.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Per-day puzzle parameters, read from a TOML file with one section per day:
+///
+/// ```toml
+/// [day14]
+/// height = 7
+/// width = 11
+/// ```
+///
+/// Each section may only set the parameters its day reads. Overrides (e.g.
+/// from the command line) apply to every day that reads them and take
+/// precedence over the file.
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    days: BTreeMap<u8, Params>,
+    overrides: Params,
+}
+
+impl Config {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let table: toml::Table = input.parse().map_err(|e: toml::de::Error| {
+            let text = e.span().map_or(&input[input.len()..], |span| &input[span]);
+            src.error(text, e.message().trim_end())
+        })?;
+        // Locate keys by their first occurrence, as the TOML parser does not keep spans
+        let find = |key: &str| input.find(key).map_or(&input[input.len()..], |i| &input[i..i + key.len()]);
+
+        let mut days = BTreeMap::new();
+        for (section, values) in table.iter() {
+            let day = section.strip_prefix("day")
+                .and_then(|num| num.parse().ok())
+                .ok_or_else(|| src.error(find(section), "expected a section named dayNN"))?;
+            let names = crate::day(day)
+                .map(|day| day.param_names())
+                .ok_or_else(|| src.error(find(section), format!("day {} is not solved (yet)", day)))?;
+            let values = values.as_table()
+                .ok_or_else(|| src.error(find(section), "expected a [dayNN] section"))?;
+            let mut params = Params::new();
+            for (name, value) in values.iter() {
+                if !names.contains(&name.as_str()) {
+                    return Err(src.error(find(name), unknown_param(day, name, names)));
+                }
+                let value = value.as_integer()
+                    .ok_or_else(|| src.error(find(name), "expected an integer value"))?;
+                params.set(name, value);
+            }
+            days.insert(day, params);
+        }
+        Ok(Self { days, overrides: Params::new() })
+    }
+
+    /// Load the config from `path`. A missing file gives an empty config.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Override a parameter for every day that reads it. No day reading it is
+    /// an error, as it is most likely misspelt.
+    pub fn set_override(&mut self, name: &str, value: i64) -> Result<(), String> {
+        if !DAYS.iter().any(|day| day.param_names().contains(&name)) {
+            let mut names: Vec<&str> = DAYS.iter().flat_map(|day| day.param_names()).copied().collect();
+            names.sort();
+            return Err(format!("no day has a parameter named {} (they have: {})", name, names.join(", ")));
+        }
+        self.overrides.set(name, value);
+        Ok(())
+    }
+
+    /// The parameters for `day`: its section of the file, then the overrides
+    /// it reads.
+    pub fn params(&self, day: u8) -> Params {
+        let mut params = self.days.get(&day).cloned().unwrap_or_default();
+        let names = crate::day(day).map_or(&[][..], |day| day.param_names());
+        for (name, value) in self.overrides.iter().filter(|(name, _)| names.contains(name)) {
+            params.set(name, value);
+        }
+        params
+    }
+}
+
+fn unknown_param(day: u8, name: &str, names: &[&str]) -> String {
+    match names {
+        [] => format!("day {} has no parameters, so cannot set {}", day, name),
+        _ => format!("day {} has no parameter named {} (it has: {})", day, name, names.join(", ")),
+    }
+}
@@ -0,0 +1,69 @@
+//! Day 1: Historian Hysteria.
+
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::{Answer, Params, ParseError, Solution, SolveError, Source};
+
+fn too_large() -> SolveError {
+    SolveError::new("the total is too large")
+}
+
+/// The two lists of location IDs, each sorted.
+pub struct Lists {
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+
+impl Solution for Lists {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for line in input.lines() {
+            let (l, r) = line.split_once(char::is_whitespace)
+                .ok_or_else(|| src.error(line, "expected two numbers separated by spaces"))?;
+            left.push(src.num::<usize>(l)?);
+            right.push(src.num::<usize>(r.trim_start())?);
+        }
+        debug!(pairs = left.len(), "parsed lists");
+        Ok(Self {
+            left: left.into_iter().sorted().collect(),
+            right: right.into_iter().sorted().collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let diff = self.left
+            .iter()
+            .zip(self.right.iter())
+            .try_fold(0usize, |sum, (l, r)| sum.checked_add(l.abs_diff(*r)))
+            .ok_or_else(too_large)?;
+        Ok(diff.into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        let similarity = self.left
+            .iter()
+            .try_fold(0usize, |sum, num| {
+                sum.checked_add(num.checked_mul(self.right.iter().filter(|x| *x == num).count())?)
+            })
+            .ok_or_else(too_large)?;
+        Ok(similarity.into())
+    }
+}
+
+/// Parse the two lists of location IDs.
+pub fn parse(input: &str, params: &Params) -> Result<Lists, ParseError> {
+    Lists::from_input(input, params)
+}
+
+/// The total distance between the lists, pairing up the smallest IDs first.
+pub fn solve_part1(lists: &Lists) -> Result<Answer, SolveError> {
+    lists.part1()
+}
+
+/// The similarity score: each ID on the left times the number of times it appears on the right.
+pub fn solve_part2(lists: &Lists) -> Result<Answer, SolveError> {
+    lists.part2()
+}
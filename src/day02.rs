@@ -0,0 +1,83 @@
+//! Day 2: Red-Nosed Reports.
+
+use tracing::trace;
+
+use crate::{Answer, Params, ParseError, Solution, SolveError, Source};
+
+/// Whether the levels all increase or all decrease, by 1 to 3 at each step.
+pub fn is_safe(nums: &Vec<i32>) -> bool {
+    fn inner(nums: &Vec<i32>) -> bool {
+        nums.windows(2).all(|win| win[1].checked_sub(win[0]).is_some_and(|step| (1..=3).contains(&step)))
+    }
+
+    let rev: Vec<i32> = nums.iter().rev().cloned().collect();
+    inner(nums) || inner(&rev)
+}
+
+/// Whether the levels are safe once at most one of them is removed.
+pub fn is_safe_with_dampener(nums: &Vec<i32>) -> bool {
+    // Only the two levels around the first bad step can be worth removing,
+    // since any other removal leaves that step in place
+    fn inner(nums: &[i32]) -> bool {
+        let is_step = |a: i32, b: i32| b.checked_sub(a).is_some_and(|step| (1..=3).contains(&step));
+        match nums.windows(2).position(|win| !is_step(win[0], win[1])) {
+            None => true,
+            Some(i) => [i, i + 1].into_iter().any(|skip| {
+                let rest = [&nums[..skip], &nums[skip + 1..]].concat();
+                rest.windows(2).all(|win| is_step(win[0], win[1]))
+            }),
+        }
+    }
+
+    let rev: Vec<i32> = nums.iter().rev().cloned().collect();
+    inner(nums) || inner(&rev)
+}
+
+/// The reports, each a list of levels.
+pub struct Reports {
+    reports: Vec<Vec<i32>>,
+}
+
+impl Solution for Reports {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let reports = input
+            .lines()
+            .map(|line| line.split(" ").map(|s| src.num::<i32>(s)).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { reports })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(self.reports
+            .iter()
+            .filter(|nums| is_safe(nums))
+            .inspect(|nums| trace!(?nums, "safe"))
+            .count()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(self.reports
+            .iter()
+            .filter(|nums| is_safe_with_dampener(nums))
+            .inspect(|nums| trace!(?nums, "safe with dampener"))
+            .count()
+            .into())
+    }
+}
+
+/// Parse one report of levels per line.
+pub fn parse(input: &str, params: &Params) -> Result<Reports, ParseError> {
+    Reports::from_input(input, params)
+}
+
+/// The number of safe reports.
+pub fn solve_part1(reports: &Reports) -> Result<Answer, SolveError> {
+    reports.part1()
+}
+
+/// The number of reports that are safe with at most one level removed.
+pub fn solve_part2(reports: &Reports) -> Result<Answer, SolveError> {
+    reports.part2()
+}
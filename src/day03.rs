@@ -0,0 +1,67 @@
+//! Day 3: Mull It Over.
+
+use regex::Regex;
+use tracing::trace;
+
+use crate::{Answer, Params, ParseError, Solution, SolveError};
+
+/// The corrupted memory, with the pattern for the instructions in it.
+pub struct Memory {
+    memory: String,
+    re: Regex,
+}
+
+impl Solution for Memory {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|(d)(o)n't\(\)|(d)(o)\(\)").unwrap();
+        Ok(Self { memory: input.to_string(), re })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let mut part1: Vec<u64> = vec![];
+        for (all, [a, b]) in self.re.captures_iter(&self.memory).map(|c| c.extract()) {
+            if all.starts_with("mul") {
+                trace!(instruction = all, "multiply");
+                part1.push(
+                    a.parse::<u64>().unwrap() * b.parse::<u64>().unwrap()
+                );
+            }
+        }
+        Ok(part1.iter().sum::<u64>().into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        let mut part2: Vec<u64> = vec![];
+        let mut on = true;
+        for (all, [a, b]) in self.re.captures_iter(&self.memory).map(|c| c.extract()) {
+            trace!(instruction = all, enabled = on);
+            if all == "don't()" {
+                on = false;
+            }
+            else if all == "do()" {
+                on = true;
+            }
+            else if all.starts_with("mul") && on {
+                part2.push(
+                    a.parse::<u64>().unwrap() * b.parse::<u64>().unwrap()
+                );
+            }
+        }
+        Ok(part2.iter().sum::<u64>().into())
+    }
+}
+
+/// Parse the corrupted memory.
+pub fn parse(input: &str, params: &Params) -> Result<Memory, ParseError> {
+    Memory::from_input(input, params)
+}
+
+/// The sum of the products of every `mul(X,Y)` instruction.
+pub fn solve_part1(memory: &Memory) -> Result<Answer, SolveError> {
+    memory.part1()
+}
+
+/// The sum of the products of the `mul(X,Y)` instructions enabled by `do()` and `don't()`.
+pub fn solve_part2(memory: &Memory) -> Result<Answer, SolveError> {
+    memory.part2()
+}
@@ -0,0 +1,77 @@
+//! Day 4: Ceres Search.
+
+use aho_corasick::AhoCorasick;
+use tracing::debug;
+
+use crate::{Answer, Grid, Params, ParseError, Pos, Solution, SolveError};
+
+fn to_string<'a>(cells: impl Iterator<Item = &'a u8>) -> String {
+    cells.map(|b| *b as char).collect()
+}
+
+/// The grid of letters to search.
+pub struct WordSearch {
+    grid: Grid<u8>,
+}
+
+impl Solution for WordSearch {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        Ok(Self { grid: Grid::parse(input.lines(), |_, byte| byte)? })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let grid = &self.grid;
+        let lines: Vec<String> = grid.rows().map(|row| to_string(row.iter()))
+            .chain(grid.columns().map(to_string))
+            .chain(grid.diagonals().map(to_string))
+            .chain(grid.anti_diagonals().map(to_string))
+            .collect();
+        let haystack: Vec<String> = lines
+            .iter()
+            .cloned()
+            .chain(lines.iter().map(|line| line.chars().rev().collect()))
+            .collect();
+
+        debug!(lines = haystack.len(), "searching every direction");
+        let needle = "XMAS";
+        let matcher = AhoCorasick::new(&[needle]).unwrap();
+        Ok(haystack
+            .iter()
+            .map(|line| matcher.find_iter(line).map(|_| 1).sum::<usize>())
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        let grid = &self.grid;
+        let at = |pos: Pos, y: i64, x: i64| grid.get(pos + Pos { y, x }).copied();
+        Ok(grid
+            .iter()
+            .filter(|(_, byte)| **byte == b'A')
+            .filter(|(pos, _)|
+                (at(*pos, -1, -1) == Some(b'M') && at(*pos, 1, 1) == Some(b'S')) ||
+                (at(*pos, -1, -1) == Some(b'S') && at(*pos, 1, 1) == Some(b'M'))
+            )
+            .filter(|(pos, _)|
+                (at(*pos, -1, 1) == Some(b'M') && at(*pos, 1, -1) == Some(b'S')) ||
+                (at(*pos, -1, 1) == Some(b'S') && at(*pos, 1, -1) == Some(b'M'))
+            )
+            .count()
+            .into())
+    }
+}
+
+/// Parse the word search grid.
+pub fn parse(input: &str, params: &Params) -> Result<WordSearch, ParseError> {
+    WordSearch::from_input(input, params)
+}
+
+/// The number of times XMAS appears in any direction.
+pub fn solve_part1(search: &WordSearch) -> Result<Answer, SolveError> {
+    search.part1()
+}
+
+/// The number of MAS crosses in the shape of an X.
+pub fn solve_part2(search: &WordSearch) -> Result<Answer, SolveError> {
+    search.part2()
+}
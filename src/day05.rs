@@ -0,0 +1,122 @@
+//! Day 5: Print Queue.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use tracing::{debug, trace};
+
+use crate::{Answer, Params, ParseError, Solution, SolveError, Source};
+
+/// A page ordering rule: `before` must be printed before `after`.
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct Rule {
+    pub before: usize,
+    pub after: usize,
+}
+
+/// The ordering rules and the updates, each a list of pages.
+pub struct Manual {
+    rules: HashSet<Rule>,
+    updates: Vec<Vec<usize>>,
+}
+
+impl Manual {
+    /// Whether a rule puts page `a` before page `b`.
+    pub fn is_sorted_by_rules(&self, a: &usize, b: &usize) -> bool {
+        self.rules.contains(&Rule {before: *a, after: *b})
+    }
+
+    /// The order of two pages, for sorting an update.
+    pub fn compare_by_rules(&self, a: &usize, b: &usize) -> Ordering {
+        if self.rules.contains(&Rule {before: *a, after: *b}) { Ordering::Less }
+        else { Ordering::Greater }
+    }
+
+    /// Sorting an update needs a rule for every pair of its pages, all
+    /// agreeing on a single order.
+    fn check_update(&self, src: Source, line: &str, update: &[usize]) -> Result<(), ParseError> {
+        let mut num_before = vec![0; update.len()];
+        for (i, a) in update.iter().enumerate() {
+            for b in update[i + 1..].iter() {
+                match (self.is_sorted_by_rules(a, b), self.is_sorted_by_rules(b, a)) {
+                    _ if a == b => return Err(src.error(line, format!("page {} appears twice", a))),
+                    (true, false) => num_before[i] += 1,
+                    (false, true) => (),
+                    (false, false) => return Err(src.error(line, format!("no rule orders pages {} and {}", a, b))),
+                    (true, true) => return Err(src.error(line, format!("pages {} and {} are ordered both ways", a, b))),
+                }
+            }
+        }
+        // With one rule per pair, the order is consistent exactly when each page
+        // comes before a different number of the others
+        for (i, a) in update.iter().enumerate() {
+            num_before[i] += update[..i].iter().filter(|b| self.is_sorted_by_rules(a, b)).count();
+        }
+        match num_before.iter().collect::<HashSet<_>>().len() == update.len() {
+            true => Ok(()),
+            false => Err(src.error(line, "the rules for these pages go round in a circle")),
+        }
+    }
+}
+
+impl Solution for Manual {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let (first, second) = src.two_sections(input)?;
+        let rules: HashSet<Rule> = first
+            .split("\n")
+            .map(|line| {
+                let (before, after) = src.split_once(line, "|")?;
+                Ok(Rule { before: src.num(before)?, after: src.num(after)? })
+            })
+            .collect::<Result<_, _>>()?;
+
+        debug!(rules = rules.len(), "parsed rules");
+        let mut manual = Self { rules, updates: vec![] };
+        for line in second.split("\n") {
+            let update: Vec<usize> = line.split(",").map(|w| src.num::<usize>(w)).collect::<Result<_, _>>()?;
+            manual.check_update(src, line, &update)?;
+            manual.updates.push(update);
+        }
+        Ok(manual)
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(self.updates
+            .iter()
+            .filter(|update| update.is_sorted_by(|a, b| self.is_sorted_by_rules(a, b)))
+            .map(|update| update[update.len() / 2])
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(self.updates
+            .iter()
+            .filter(|update| !update.is_sorted_by(|a, b| self.is_sorted_by_rules(a, b)))
+            .map(|update| {
+                let mut fix_update = update.clone();
+                fix_update.sort_by(|a, b| self.compare_by_rules(a, b));
+                trace!(?update, fixed = ?fix_update, "reordered");
+                fix_update
+            })
+            .map(|update| update[update.len() / 2])
+            .sum::<usize>()
+            .into())
+    }
+}
+
+/// Parse the page ordering rules and the updates.
+pub fn parse(input: &str, params: &Params) -> Result<Manual, ParseError> {
+    Manual::from_input(input, params)
+}
+
+/// The sum of the middle pages of the updates already in order.
+pub fn solve_part1(manual: &Manual) -> Result<Answer, SolveError> {
+    manual.part1()
+}
+
+/// The sum of the middle pages of the out-of-order updates, once put in order.
+pub fn solve_part2(manual: &Manual) -> Result<Answer, SolveError> {
+    manual.part2()
+}
@@ -0,0 +1,203 @@
+//! Day 6: Guard Gallivant.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use itertools::Itertools;
+use tracing::{debug, trace};
+
+use crate::{
+    parallel, Answer, Color, Cycle, Dir, Event, Events, Frame, Frames, Grid, Outcome, Palette,
+    Params, ParseError, Pos, Simulation, Solution, SolveError, Source,
+};
+
+/// Where the guard is and which way they are facing.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Step {
+    pub pos: Pos,
+    pub dir: Dir,
+}
+
+/// The lab map, with `true` for obstructions, and where the guard starts.
+#[derive(Debug)]
+pub struct World {
+    map: Grid<bool>,
+    start: Pos
+}
+
+impl World {
+    fn parse_map(src: Source) -> Result<Self, ParseError> {
+        let mut start = None;
+        let map = Grid::try_parse(src.lines(), |pos, byte| match byte {
+            b'^' => { start = Some(pos); Some(false) },  // found start point
+            b'#' => Some(true),
+            b'.' => Some(false),
+            _ => None,
+        })?;
+        let start = start.ok_or_else(|| src.error_at_end("no guard ('^') found"))?;
+        Ok(Self { map, start })
+    }
+
+    fn occupied(&self, p: Pos) -> Option<bool> {
+        self.map.get(p).copied()
+    }
+
+    fn next_step(&self, step: Step) -> Option<Step> {
+        let pos = step.pos + step.dir.pos();
+        match self.occupied(pos) {
+            None => None,
+            Some(true) => Some(Step { pos: step.pos, dir: step.dir.turn_right() }),
+            Some(false) => Some(Step { pos, dir: step.dir }),
+        }
+    }
+
+    /// The guard's patrol from `start`, which ends when they leave the map.
+    pub fn simulation(&self, start: Step) -> Simulation<Step, impl Fn(&Step) -> Option<Step> + use<'_>> {
+        Simulation::new(start, |step| self.next_step(*step))
+    }
+
+    /// The guard's steps from `start` until they leave the map, ending with
+    /// `Err` if they come back to a step they have already taken.
+    pub fn patrol(&self, start: Step) -> impl Iterator<Item = Result<Step, Cycle>> + use<'_> {
+        self.simulation(start).states()
+    }
+
+    /// Whether the guard patrols from `start` in a loop, never leaving the map.
+    pub fn loops(&self, start: Step) -> bool {
+        matches!(self.simulation(start).brent().run(), Outcome::Cycle(_))
+    }
+
+    /// The same lab with an obstruction added at `pos`.
+    pub fn add_obstruction(&self, pos: Pos) -> Self {
+        let mut map = self.map.clone();
+        map[pos] = true;
+        Self { map, start: self.start }
+    }
+
+    /// Where the guard starts, facing up.
+    pub fn start_step(&self) -> Step {
+        Step { pos: self.start, dir: Dir::Up }
+    }
+
+    /// Every step of the guard's patrol, with its index.
+    pub fn path(&self) -> Result<HashMap<Step, usize>, SolveError> {
+        self.patrol(self.start_step())
+            .enumerate()
+            .map(|(n, step)| match step {
+                Ok(step) => Ok((step, n)),
+                Err(_) => Err(SolveError::new("the guard patrols in a loop and never leaves the map")),
+            })
+            .collect()
+    }
+
+    fn frame(&self, visited: &HashSet<Pos>) -> Frame {
+        let mut frame = Frame::new(&self.map, |_, wall| if *wall { '#' } else { '.' });
+        for pos in visited.iter() {
+            frame.draw(*pos, 'X', Color::Yellow);
+        }
+        frame
+    }
+}
+
+impl Solution for World {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        Self::parse_map(Source::new(input))
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let path = self.path()?;
+        debug!(steps = path.len(), "guard left the map");
+        Ok(path.keys().unique_by(|step| step.pos).count().into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        // For each step on the path, add an obstruction and see if it causes a loop
+        let start = self.start_step();
+        let candidates: Vec<Pos> = self.path()?.keys().map(|step| step.pos).unique().collect();
+        let loops = parallel::filter(candidates, |pos| {
+            let looped = self.add_obstruction(*pos).loops(start);
+            if looped {
+                trace!(?pos, "obstruction causes a loop");
+            }
+            looped
+        });
+        Ok(loops.len().into())
+    }
+
+    fn visualize(&self, part: u8) -> Option<Frames<'_>> {
+        let start = self.start_step();
+        let mut visited = HashSet::new();
+        match part {
+            // The guard walking its patrol
+            1 => Some(Box::new(self.patrol(start).map_while(Result::ok).map(move |step| {
+                visited.insert(step.pos);
+                let mut frame = self.frame(&visited);
+                frame.draw(step.pos, step.dir.arrow(), Color::Red);
+                frame.with_caption(format!("{} positions visited", visited.len()))
+            }))),
+            // Each obstruction tried along the path, keeping those that cause a loop
+            2 => {
+                let path: Vec<Pos> = self.patrol(start).map_while(Result::ok).map(|step| step.pos).unique().collect();
+                visited.extend(path.iter().copied());
+                let mut loops = vec![];
+                Some(Box::new(path.into_iter().map(move |pos| {
+                    if self.add_obstruction(pos).loops(start) {
+                        loops.push(pos);
+                    }
+                    let mut frame = self.frame(&visited);
+                    for pos in loops.iter() {
+                        frame.draw(*pos, 'O', Color::Green);
+                    }
+                    frame.draw(pos, 'O', Color::Red);
+                    frame.with_caption(format!("{} obstructions cause a loop", loops.len()))
+                })))
+            },
+            _ => None,
+        }
+    }
+
+    fn trace(&self, part: u8) -> Option<Events<'_>> {
+        let start = self.start_step();
+        match part {
+            // Each step of the patrol, and where it starts looping if it does
+            1 => {
+                let mut dir = start.dir;
+                Some(Box::new(self.patrol(start).map(move |step| match step {
+                    Ok(step) => {
+                        let kind = if step.dir == dir { "step" } else { "turn" };
+                        dir = step.dir;
+                        Event::new(kind).with_pos("pos", step.pos).with("dir", step.dir.arrow())
+                    },
+                    Err(Cycle { start, period }) => Event::new("loop").with("start", start).with("period", period),
+                })))
+            },
+            // Each obstruction tried along the path
+            2 => {
+                let path: Vec<Pos> = self.patrol(start).map_while(Result::ok).map(|step| step.pos).unique().collect();
+                Some(Box::new(path.into_iter().map(move |pos| {
+                    let loops = self.add_obstruction(pos).loops(start);
+                    Event::new("obstruction").with_pos("pos", pos).with("loops", loops)
+                })))
+            },
+            _ => None,
+        }
+    }
+
+    fn palette(&self) -> Palette {
+        Palette { foreground: [120, 110, 100], colors: &[(Color::Yellow, [250, 190, 70])], ..Palette::DEFAULT }
+    }
+}
+
+/// Parse the lab map with the guard's starting position.
+pub fn parse(input: &str, params: &Params) -> Result<World, ParseError> {
+    World::from_input(input, params)
+}
+
+/// The number of distinct positions the guard visits before leaving the map.
+pub fn solve_part1(world: &World) -> Result<Answer, SolveError> {
+    world.part1()
+}
+
+/// The number of positions where a new obstruction would trap the guard in a loop.
+pub fn solve_part2(world: &World) -> Result<Answer, SolveError> {
+    world.part2()
+}
@@ -0,0 +1,121 @@
+//! Day 7: Bridge Repair.
+
+use tracing::trace;
+
+use crate::{parallel, Answer, Params, ParseError, Solution, SolveError, Source};
+
+/// An operator that can go between the numbers of an equation.
+#[derive(Clone, Debug)]
+pub enum Op {
+    Add,
+    Mul,
+    Cat,
+}
+
+impl Op {
+    /// The left operand `a` such that `a op b == result`, if there is one.
+    pub fn unapply(&self, result: u64, b: u64) -> Option<u64> {
+        match self {
+            Self::Add => result.checked_sub(b),
+            Self::Mul => match b {
+                0 => None,  // Handled by the caller, as any `a` works for a result of 0
+                _ => result.is_multiple_of(b).then_some(result / b),
+            },
+            Self::Cat => {
+                let rest = result.checked_sub(b)?;
+                match 10u64.checked_pow(b.checked_ilog10().unwrap_or(0) + 1) {
+                    Some(shift) => rest.is_multiple_of(shift).then_some(rest / shift),
+                    // `b` has 20 digits, so nothing can go in front of it
+                    None => (rest == 0).then_some(0),
+                }
+            },
+        }
+    }
+}
+
+/// A calibration equation: `result` should come from combining `inputs`
+/// from left to right.
+#[derive(Clone, Debug)]
+pub struct Equation {
+    pub result: u64,
+    pub inputs: Vec<u64>,
+}
+
+impl Equation {
+    fn parse(src: Source, line: &str) -> Result<Self, ParseError> {
+        let (lhs, rest) = src.split_once(line, ": ")?;
+        Ok(Self {
+            result: src.num(lhs)?,
+            inputs: rest.split(" ").map(|s| src.num::<u64>(s)).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Whether some choice of `ops` between the inputs gives the result.
+    pub fn is_solvable(&self, ops: &[Op]) -> bool {
+        // Work backwards from the result, undoing the last operation each time,
+        // which rules out most operators right away
+        fn inner(result: u64, inputs: &[u64], ops: &[Op]) -> bool {
+            match inputs {
+                [] => false,
+                [first] => result == *first,
+                [rest @ .., last] => ops.iter().any(|op| match (op, last) {
+                    (Op::Mul, 0) => result == 0,
+                    _ => op.unapply(result, *last).is_some_and(|a| inner(a, rest, ops)),
+                }),
+            }
+        }
+
+        inner(self.result, &self.inputs, ops)
+    }
+}
+
+fn solve(eqs: &Vec<Equation>, ops: &[Op]) -> Result<u64, SolveError> {
+    let solvable = parallel::filter(eqs.iter().collect(), |eq| eq.is_solvable(ops));
+    solvable
+        .into_iter()
+        .inspect(|eq| trace!(result = eq.result, inputs = ?eq.inputs, "solvable"))
+        .try_fold(0u64, |sum, eq| sum.checked_add(eq.result))
+        .ok_or_else(|| SolveError::new("the total calibration result is too large"))
+}
+
+/// All the calibration equations.
+pub struct Equations {
+    eqs: Vec<Equation>,
+}
+
+impl Equations {
+    pub fn equations(&self) -> &[Equation] {
+        &self.eqs
+    }
+}
+
+impl Solution for Equations {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let eqs = input.lines().map(|line| Equation::parse(src, line)).collect::<Result<_, _>>()?;
+        Ok(Self { eqs })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(solve(&self.eqs, &[Op::Add, Op::Mul])?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(solve(&self.eqs, &[Op::Add, Op::Mul, Op::Cat])?.into())
+    }
+}
+
+/// Parse one calibration equation per line.
+pub fn parse(input: &str, params: &Params) -> Result<Equations, ParseError> {
+    Equations::from_input(input, params)
+}
+
+/// The total of the results that can be made with `+` and `*`.
+pub fn solve_part1(equations: &Equations) -> Result<Answer, SolveError> {
+    equations.part1()
+}
+
+/// The total of the results that can be made with `+`, `*` and concatenation.
+pub fn solve_part2(equations: &Equations) -> Result<Answer, SolveError> {
+    equations.part2()
+}
@@ -0,0 +1,141 @@
+//! Day 8: Resonant Collinearity.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use gcd::Gcd;
+use itertools::Either::{Left, Right};
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::{Answer, Color, Frame, Frames, Grid, Params, ParseError, Pos, Solution, SolveError};
+
+/// The size of the map and the antennas on it, by frequency.
+#[derive(Debug)]
+pub struct Map {
+    size: Pos,
+    antennas: HashMap<char, HashSet<Pos>>,
+}
+
+impl Map {
+    fn parse_map<'a, I>(lines: I) -> Result<Self, ParseError>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let grid = Grid::try_parse(lines, |_, byte| match byte {
+            b'.' => Some(byte),
+            _ if byte.is_ascii_alphanumeric() => Some(byte),
+            _ => None,
+        })?;
+        let mut antennas: HashMap<char, HashSet<Pos>> = HashMap::new();
+        for (pos, byte) in grid.iter().filter(|(_, byte)| **byte != b'.') {
+            antennas.entry(*byte as char).or_default().insert(pos);
+        }
+        for (freq, positions) in antennas.iter() {
+            debug!(%freq, antennas = positions.len());
+        }
+        Ok(Self { size: grid.size(), antennas })
+    }
+
+    fn contains(&self, p: Pos) -> bool {
+        p.y >= 0 && p.y < self.size.y && p.x >= 0 && p.x < self.size.x
+    }
+
+    fn part1_antinodes(&self, a: Pos, b: Pos) -> impl Iterator<Item = Pos> {
+        [(a, b), (b, a)].into_iter().map(|(a, b)| b + (b - a))
+    }
+
+    fn part2_antinodes(&self, a: Pos, b: Pos) -> impl Iterator<Item = Pos> {
+        let diff = b - a;
+        let gcd= (diff.y.abs() as u64).gcd(diff.x.abs() as u64) as i64;
+        let base = Pos { y: diff.y / gcd, x: diff.x / gcd};
+        (-self.size.y..self.size.y).map(move |n| a + base * n)
+    }
+
+    fn antinodes_for_freq(&self, freq: char, part2: bool) -> impl Iterator<Item = Pos> + use<'_> {
+        let antennas = &self.antennas[&freq];
+        antennas
+            .iter()
+            .combinations(2)
+            .map(move |pair| {
+                match part2 {
+                    false => Left(self.part1_antinodes(*pair[0], *pair[1])),
+                    true => Right(self.part2_antinodes(*pair[0], *pair[1])),
+                }
+            })
+            .flatten()
+            .filter(move |pos| {
+                match part2 {
+                    false => !antennas.contains(pos),  // must not overlap with same-freq antenna
+                    true => true,
+                }
+            })
+            .filter(|pos| self.contains(*pos))  // must be within bounds
+    }
+
+    /// Every antinode on the map, once per pair of antennas that makes it.
+    /// Part 2's antinodes repeat all along the line through each pair.
+    pub fn all_antinodes(&self, part2: bool) -> impl Iterator<Item = Pos> + use<'_> {
+        self.antennas
+            .keys()
+            .map(move |c| self.antinodes_for_freq(*c, part2))
+            .flatten()
+    }
+}
+
+impl Solution for Map {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        Self::parse_map(input.lines())
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(self.all_antinodes(false).unique().count().into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(self.all_antinodes(true).unique().count().into())
+    }
+
+    /// The antinodes of one frequency at a time, added to those found so far.
+    fn visualize(&self, part: u8) -> Option<Frames<'_>> {
+        let part2 = match part {
+            1 => false,
+            2 => true,
+            _ => return None,
+        };
+        let mut antinodes = HashSet::new();
+        Some(Box::new(self.antennas.keys().sorted().map(move |freq| {
+            let new: HashSet<Pos> = self.antinodes_for_freq(*freq, part2).collect();
+            let mut frame = Frame::filled(self.size, '.');
+            for pos in antinodes.iter() {
+                frame.draw(*pos, '#', Color::Green);
+            }
+            for pos in new.iter() {
+                frame.draw(*pos, '#', Color::Red);
+            }
+            for (other, positions) in self.antennas.iter() {
+                let color = if other == freq { Color::Yellow } else { Color::Gray };
+                for pos in positions.iter() {
+                    frame.draw(*pos, *other, color);
+                }
+            }
+            antinodes.extend(new);
+            frame.with_caption(format!("Frequency {}: {} antinodes so far", freq, antinodes.len()))
+        })))
+    }
+}
+
+/// Parse the map of antennas.
+pub fn parse(input: &str, params: &Params) -> Result<Map, ParseError> {
+    Map::from_input(input, params)
+}
+
+/// The number of positions with an antinode two antenna distances out.
+pub fn solve_part1(map: &Map) -> Result<Answer, SolveError> {
+    map.part1()
+}
+
+/// The number of positions with an antinode anywhere in line with two antennas.
+pub fn solve_part2(map: &Map) -> Result<Answer, SolveError> {
+    map.part2()
+}
@@ -1,8 +1,12 @@
+//! Day 9: Disk Fragmenter.
+
 use std::collections::VecDeque;
-use std::io;
-use std::io::Read;
 use std::iter;
 
+use tracing::trace;
+
+use crate::{Answer, Params, ParseError, Solution, SolveError, Source};
+
 fn part1_expand(files: &Vec<usize>, frees: &Vec<usize>) -> Vec<usize> {
     assert_eq!(files.len(), frees.len());
     let mut result: Vec<usize> = Vec::with_capacity(files.iter().sum());
@@ -92,8 +96,9 @@ fn part2_expand(files: &Vec<usize>, frees: &Vec<usize>) -> Vec<usize> {
         };
         // Move fileblock from file_i to ins_i
         let moving = fileblocks.remove(extract_i);
+        trace!(id = moving.id, size = moving.size, from = extract_i, to = insert_i + 1, "move file");
         let free_after = freeblocks.remove(extract_i);
-        assert!(insert_i + 1 < freeblocks.len());
+        assert!(insert_i < freeblocks.len());
         let (moved, shrunk_free) = moving.split(&freeblocks[insert_i]);
         fileblocks.insert(insert_i + 1, moved);
         // Adjust freeblocks around insertion point: 0 before, free.size - file.size after
@@ -111,28 +116,63 @@ fn part2_expand(files: &Vec<usize>, frees: &Vec<usize>) -> Vec<usize> {
         .collect()
 }
 
-fn checksum(blocks: Vec<usize>) -> usize {
+/// The filesystem checksum of the file ID in each block (0 for free ones).
+pub fn checksum(blocks: Vec<usize>) -> usize {
     blocks.iter().enumerate().map(|(i, id)| i * id).sum::<usize>()
 }
 
-fn main() {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    let nums: Vec<usize> = input
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as usize)
-        .collect();
+/// The sizes of the files and of the free spans after each of them.
+pub struct DiskMap {
+    files: Vec<usize>,
+    frees: Vec<usize>,
+}
+
+impl Solution for DiskMap {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let line = input.trim();
+        let nums: Vec<usize> = line
+            .char_indices()
+            .map(|(i, c)| match c.to_digit(10) {
+                Some(d) => Ok(d as usize),
+                None => Err(src.error(&line[i..i + c.len_utf8()], "expected a digit")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        if nums.is_empty() {
+            return Err(src.error_at_end("expected a line of digits"));
+        }
+        let (files, frees): (Vec<usize>, Vec<usize>) = nums
+            .chunks(2)
+            .map(|arr| match arr {
+                [a, b] => (*a, *b),
+                [a] => (*a, 0),
+                _ => unreachable!(),
+            })
+            .unzip();
+        Ok(Self { files, frees })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(checksum(part1_expand(&self.files, &self.frees)).into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(checksum(part2_expand(&self.files, &self.frees)).into())
+    }
+}
+
+/// Parse the dense disk map.
+pub fn parse(input: &str, params: &Params) -> Result<DiskMap, ParseError> {
+    DiskMap::from_input(input, params)
+}
+
+/// The filesystem checksum after moving single blocks into the leftmost free space.
+pub fn solve_part1(disk: &DiskMap) -> Result<Answer, SolveError> {
+    disk.part1()
+}
 
-    let (files, frees): (Vec<usize>, Vec<usize>) = nums
-        .chunks(2)
-        .map(|arr| match arr {
-            [a, b] => (*a, *b),
-            [a] => (*a, 0),
-            _ => panic!(),
-        })
-        .unzip();
-
-    println!("Part 1: {}", checksum(part1_expand(&files, &frees)));
-    println!("Part 2: {}", checksum(part2_expand(&files, &frees)));
+/// The filesystem checksum after moving whole files into the leftmost free span that fits.
+pub fn solve_part2(disk: &DiskMap) -> Result<Answer, SolveError> {
+    disk.part2()
 }
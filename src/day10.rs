@@ -0,0 +1,106 @@
+//! Day 10: Hoof It.
+
+use std::collections::{HashMap, HashSet};
+
+use tracing::trace;
+
+use crate::{Answer, Grid, Params, ParseError, Pos, Solution, SolveError};
+
+/// The height of each position on the map.
+#[derive(Debug)]
+pub struct Map {
+    map: Grid<u8>,
+}
+
+impl Map {
+    fn parse_map<'a, I>(lines: I) -> Result<Self, ParseError>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let map = Grid::try_parse(lines, |_, byte| match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            _ => None,
+        })?;
+        Ok(Self { map })
+    }
+
+    /// The positions at height 0.
+    pub fn trailheads(&self) -> HashSet<Pos> {
+        self.map.iter().filter(|(_, height)| **height == 0).map(|(pos, _)| pos).collect()
+    }
+
+    fn next(&self, height: u8, from: Pos) -> impl Iterator<Item = Pos> + use<'_> {
+        assert!(height < 10);
+        from.adjacents().filter(move |p| self.map.get(*p) == Some(&height))
+    }
+
+    /// The number of height-9 positions reachable from `trailhead`.
+    pub fn score(&self, trailhead: Pos) -> usize {
+        let mut paths: HashSet<Pos> = HashSet::new();
+        paths.insert(trailhead);
+        for level in 1..10 {
+            let mut next_paths: HashSet<Pos> = HashSet::new();
+            for path in paths {
+                next_paths.extend(self.next(level, path));
+            }
+            paths = next_paths
+        }
+        paths.len()
+    }
+
+    /// The number of distinct trails from `trailhead` to height 9.
+    pub fn rate(&self, trailhead: Pos) -> usize {
+        // Count the trails reaching each position, rather than listing them
+        let mut paths: HashMap<Pos, usize> = HashMap::new();
+        paths.insert(trailhead, 1);
+        for level in 1..10 {
+            let mut next_paths: HashMap<Pos, usize> = HashMap::new();
+            for (pos, count) in paths {
+                for next in self.next(level, pos) {
+                    *next_paths.entry(next).or_insert(0) += count;
+                }
+            }
+            paths = next_paths
+        }
+        paths.values().sum()
+    }
+}
+
+impl Solution for Map {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        Self::parse_map(input.lines())
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(self.trailheads()
+            .iter()
+            .map(|head| self.score(*head))
+            .inspect(|score| trace!(score, "trailhead"))
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(self.trailheads()
+            .iter()
+            .map(|head| self.rate(*head))
+            .inspect(|rating| trace!(rating, "trailhead"))
+            .sum::<usize>()
+            .into())
+    }
+}
+
+/// Parse the topographic map.
+pub fn parse(input: &str, params: &Params) -> Result<Map, ParseError> {
+    Map::from_input(input, params)
+}
+
+/// The sum of the trailhead scores: how many 9s each trailhead reaches.
+pub fn solve_part1(map: &Map) -> Result<Answer, SolveError> {
+    map.part1()
+}
+
+/// The sum of the trailhead ratings: how many distinct trails start at each trailhead.
+pub fn solve_part2(map: &Map) -> Result<Answer, SolveError> {
+    map.part2()
+}
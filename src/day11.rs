@@ -0,0 +1,106 @@
+//! Day 11: Plutonian Pebbles.
+
+use std::collections::HashMap;
+
+use tracing::debug;
+
+use crate::{Answer, Params, ParseError, Solution, SolveError, Source};
+
+/// The number of stones with each number engraved on them.
+pub type Stones = HashMap<usize, usize>;
+
+fn too_large() -> SolveError {
+    SolveError::new("the stones grow too large to count")
+}
+
+fn blink_one(num: usize) -> Result<Vec<usize>, SolveError> {
+    Ok(match num {
+        0 => vec![1],
+        n if (n.ilog10() + 1) % 2 == 0 => {
+            let s = num.to_string();
+            vec![
+                s[..s.len() / 2].parse::<usize>().unwrap(),
+                s[s.len() / 2..].parse::<usize>().unwrap(),
+            ]
+        },
+        _ => vec![num.checked_mul(2024).ok_or_else(too_large)?],
+    })
+}
+
+/// The stones after blinking once.
+pub fn blink(stones: &Stones) -> Result<Stones, SolveError> {
+    let mut ret: Stones = HashMap::new();
+    for (stone, count) in stones.iter() {
+        for new_stone in blink_one(*stone)? {
+            let total = ret.entry(new_stone).or_insert(0);
+            *total = total.checked_add(*count).ok_or_else(too_large)?;
+        }
+    }
+    Ok(ret)
+}
+
+/// The number of stones after blinking `times` times.
+pub fn blink_times(stones: &Stones, times: usize) -> Result<usize, SolveError> {
+    let mut stones = stones.clone();
+    for blinks in 1..=times {
+        stones = blink(&stones)?;
+        debug!(blinks, distinct = stones.len());
+    }
+    stones.values().try_fold(0usize, |sum, count| sum.checked_add(*count)).ok_or_else(too_large)
+}
+
+/// The stones, and how many times to blink in each part.
+pub struct Arrangement {
+    stones: Stones,
+    part1_blinks: usize,
+    part2_blinks: usize,
+}
+
+impl Arrangement {
+    pub fn stones(&self) -> &Stones {
+        &self.stones
+    }
+}
+
+impl Solution for Arrangement {
+    fn parse(input: &str, params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let line = input.lines().next().ok_or_else(|| src.error_at_end("expected a line of stones"))?;
+        let mut stones: Stones = HashMap::new();
+        for s in line.split(" ") {
+            *stones.entry(src.num::<usize>(s)?).or_insert(0) += 1;
+        }
+        Ok(Self {
+            stones,
+            part1_blinks: params.get_nonneg(src, "part1_blinks", 25)?,
+            part2_blinks: params.get_nonneg(src, "part2_blinks", 75)?,
+        })
+    }
+
+    fn param_names() -> &'static [&'static str] {
+        &["part1_blinks", "part2_blinks"]
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(blink_times(&self.stones, self.part1_blinks)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(blink_times(&self.stones, self.part2_blinks)?.into())
+    }
+}
+
+/// Parse the line of stones.
+pub fn parse(input: &str, params: &Params) -> Result<Arrangement, ParseError> {
+    Arrangement::from_input(input, params)
+}
+
+/// The number of stones after `part1_blinks` (25) blinks.
+pub fn solve_part1(arrangement: &Arrangement) -> Result<Answer, SolveError> {
+    arrangement.part1()
+}
+
+/// The number of stones after `part2_blinks` (75) blinks.
+pub fn solve_part2(arrangement: &Arrangement) -> Result<Answer, SolveError> {
+    arrangement.part2()
+}
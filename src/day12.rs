@@ -1,104 +1,51 @@
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::hash::Hash;
-use std::io;
-use std::ops::Add;
-
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-struct Pos {
-    y: i32,
-    x: i32,
-}
-
-impl Add for Pos {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self {
-        Self {y: self.y + other.y, x: self.x + other.x}
-    }
-}
-
-impl Pos {
-    fn adjacents(&self) -> [Self; 4] {
-        [
-            Self { y: self.y - 1, x: self.x },
-            Self { y: self.y, x: self.x - 1 },
-            Self { y: self.y, x: self.x + 1 },
-            Self { y: self.y + 1, x: self.x },
-        ]
-    }
-}
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Dir {
-    Up,
-    Right,
-    Down,
-    Left,
-}
+//! Day 12: Garden Groups.
 
-impl Dir {
-    fn pos(&self) -> Pos {
-        match self {
-            Self::Up => Pos { y: -1, x: 0 },
-            Self::Right => Pos { y: 0, x: 1 },
-            Self::Down => Pos { y: 1, x: 0 },
-            Self::Left => Pos { y: 0, x: -1 },
-        }
-    }
+use std::collections::HashSet;
 
-    fn turn_left(&self) -> Self {
-        match self {
-            Self::Up => Self::Left,
-            Self::Right => Self::Up,
-            Self::Down => Self::Right,
-            Self::Left => Self::Down,
-        }
-    }
+use tracing::{debug, trace};
 
-    fn turn_right(&self) -> Self {
-        match self {
-            Self::Up => Self::Right,
-            Self::Right => Self::Down,
-            Self::Down => Self::Left,
-            Self::Left => Self::Up,
-        }
-    }
-}
+use crate::{Answer, Color, Dir, Frame, Frames, Grid, Params, ParseError, Pos, Solution, SolveError};
 
+/// A connected region of plots growing the same plant.
 #[derive(Debug, Default)]
-struct Region {
+pub struct Region {
     coords: HashSet<Pos>,
 }
 
 impl Region {
-    fn grow_from(map: &HashMap<Pos, char>, start: Pos) -> Self {
-        let name = map[&start];
+    fn grow_from(map: &Grid<u8>, start: Pos) -> Self {
+        let name = map[start];
         let mut coords: HashSet<Pos> = HashSet::new();
         let mut adjs: HashSet<Pos> = HashSet::from([start]);
         while coords.len() < adjs.len() {
             let cur = *adjs.difference(&coords).next().unwrap();
             coords.insert(cur);
-            let nbors: HashSet<Pos> = cur.adjacents().into_iter()
-                .filter(|p| map.get(p) == Some(&name)).collect();
+            let nbors: HashSet<Pos> = cur.adjacents()
+                .filter(|p| map.get(*p) == Some(&name)).collect();
             adjs = adjs.union(&nbors).cloned().collect();
         }
         Self { coords }
     }
 
-    fn area(&self) -> usize {
+    pub fn area(&self) -> usize {
         self.coords.len()
     }
 
-    fn contains(&self, pos: &Pos) -> bool {
+    pub fn contains(&self, pos: &Pos) -> bool {
         self.coords.contains(pos)
     }
 
     fn perimeter_one(&self, pos: Pos) -> usize {
-        4 - pos.adjacents().iter().filter(|p| self.contains(p)).count()
+        4 - pos.adjacents().filter(|p| self.contains(p)).count()
     }
 
-    fn perimeter(&self) -> usize {
+    /// The plots with a fence along at least one side.
+    fn edge(&self) -> impl Iterator<Item = Pos> + use<'_> {
+        self.coords.iter().copied().filter(|pos| self.perimeter_one(*pos) > 0)
+    }
+
+    /// The length of fence around the region, inside and out.
+    pub fn perimeter(&self) -> usize {
         self.coords.iter().map(|pos| self.perimeter_one(*pos)).sum()
     }
 
@@ -160,7 +107,8 @@ impl Region {
         (sides, hole)
     }
 
-    fn sides(&self) -> usize {
+    /// The number of straight sections of fence around the region, inside and out.
+    pub fn sides(&self) -> usize {
         let mut nbors: HashSet<Pos> = self.coords
             .iter()
             .flat_map(|p| p.adjacents())
@@ -178,50 +126,100 @@ impl Region {
     }
 }
 
+/// The garden, split into regions.
 #[derive(Debug)]
-struct Garden {
+pub struct Garden {
+    map: Grid<u8>,
     regions: Vec<Region>,
 }
 
 impl Garden {
-    fn _find_regions(map: &HashMap<Pos, char>) -> Self {
-        let mut remaining: HashSet<Pos> = map.keys().cloned().collect();
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    fn _find_regions(map: Grid<u8>) -> Self {
+        let mut seen: Grid<bool> = map.map(|_| false);
         let mut regions: Vec<Region> = Vec::new();
-        while !remaining.is_empty() {
-            let start = remaining.iter().next().unwrap();
-            let region = Region::grow_from(map, *start);
-            remaining = remaining.difference(&region.coords).cloned().collect();
+        for start in map.positions() {
+            if seen[start] {
+                continue;
+            }
+            let region = Region::grow_from(&map, start);
+            for pos in region.coords.iter() {
+                seen[*pos] = true;
+            }
+            trace!(plant = %(map[start] as char), area = region.area(), "region");
             regions.push(region);
         }
-        Self { regions }
+        debug!(regions = regions.len(), "found regions");
+        Self { map, regions }
+    }
+}
+
+impl Solution for Garden {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let map = Grid::try_parse(input.lines(), |_, byte| match byte {
+            b'A'..=b'Z' => Some(byte),
+            _ => None,
+        })?;
+        Ok(Self::_find_regions(map))
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(self.regions.iter().map(|r| r.area() * r.perimeter()).sum::<usize>().into())
     }
 
-    fn parse<I>(lines: I) -> Self
-    where
-        I: Iterator<Item = String>,
-    {
-        let mut bounds = Pos { y: 0, x: 0 };
-        let mut map: HashMap<Pos, char> = HashMap::new();
-        for (y, line) in lines.enumerate() {
-            bounds = Pos {
-                y: y as i32 + 1,
-                x: match bounds.x {
-                    0 => line.len() as i32,
-                    n => { assert!(n == line.len() as i32); n },
-                },
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(self.regions.iter().map(|r| r.area() * r.sides()).sum::<usize>().into())
+    }
+
+    /// Each region in turn, outlined, with the price of fencing it.
+    fn visualize(&self, part: u8) -> Option<Frames<'_>> {
+        if !(1..=2).contains(&part) {
+            return None;
+        }
+        let mut fenced = Frame::new(&self.map, |_, plant| *plant as char);
+        let mut total = 0;
+        Some(Box::new(self.regions.iter().map(move |region| {
+            let plant = self.map[*region.coords.iter().next().unwrap()] as char;
+            let (measure, name) = match part {
+                1 => (region.perimeter(), "perimeter"),
+                _ => (region.sides(), "sides"),
             };
-            for (x, byte) in line.as_bytes().into_iter().enumerate() {
-                let name = *byte as char;
-                let pos = Pos { y: y as i32, x: x as i32 };
-                map.insert(pos, name);
+            total += region.area() * measure;
+            let mut frame = fenced.clone();
+            for pos in region.coords.iter() {
+                frame.highlight(*pos, Color::Yellow);
             }
-        }
-        Self::_find_regions(&map)
+            for pos in region.edge() {
+                frame.highlight(pos, Color::Red);
+                fenced.highlight(pos, Color::Green);
+            }
+            frame.with_caption(format!(
+                "Region {}: area {} x {} {} = {}, total {}",
+                plant,
+                region.area(),
+                name,
+                measure,
+                region.area() * measure,
+                total,
+            ))
+        })))
     }
 }
 
-fn main() {
-    let garden = Garden::parse(io::stdin().lines().map(Result::unwrap));
-    println!("Part 1: {}", garden.regions.iter().map(|r| r.area() * r.perimeter()).sum::<usize>());
-    println!("Part 2: {}", garden.regions.iter().map(|r| r.area() * r.sides()).sum::<usize>());
+/// Parse the garden plot map into regions.
+pub fn parse(input: &str, params: &Params) -> Result<Garden, ParseError> {
+    Garden::from_input(input, params)
+}
+
+/// The total fencing price, by area times perimeter.
+pub fn solve_part1(garden: &Garden) -> Result<Answer, SolveError> {
+    garden.part1()
+}
+
+/// The total fencing price with the bulk discount, by area times number of sides.
+pub fn solve_part2(garden: &Garden) -> Result<Answer, SolveError> {
+    garden.part2()
 }
@@ -0,0 +1,131 @@
+//! Day 13: Claw Contraption.
+
+use regex::Regex;
+use tracing::trace;
+
+use crate::{Answer, Params, ParseError, Solution, SolveError, Source};
+
+fn float_solve(ax: f64, ay: f64, bx: f64, by: f64, px: f64, py: f64) -> (f64, f64) {
+    // 2 eqs with 2 unknowns (a, b):
+    //   Eq1: a * ax + b * bx == px
+    //   Eq2: a * ay + b * by == py
+    // Re-arrange Eq2 in terms of a:
+    //   a = (py - b * by) / ay
+    // Insert for a in Eq1:
+    //   (py - b * by) / ay * ax + b * bx == px
+    // Re-arrange in terms of b:
+    //   ax * py - b * ax * by + b * bx * ay == px * ay
+    //   b * (bx * ay - ax * by) == px * ay - py * ax
+    //   b = (px * ay - py * ax) / (bx * ay - ax * by)
+    let b = (px * ay - py * ax) / (bx * ay - ax * by);
+    let a = (py - b * by) / ay;
+    (a, b)
+}
+
+fn too_large() -> SolveError {
+    SolveError::new("the total number of tokens is too large")
+}
+
+/// The tokens needed to win the prize of a machine, if it can be won: A moves
+/// the claw by (ax, ay), B by (bx, by), and the prize is at (px, py). Fails if
+/// the tokens don't fit in a u64.
+pub fn solve(
+    ax: u64, ay: u64, bx: u64, by: u64, px: u64, py: u64, a_cost: u64, b_cost: u64,
+) -> Result<Option<u64>, SolveError> {
+    let (fa, fb) = float_solve(ax as f64, ay as f64, bx as f64, by as f64, px as f64, py as f64);
+    let (a, b) = (fa as u64, fb as u64);
+    trace!(px, py, a, b, "presses");
+    let reach = |a_step: u64, b_step: u64| a.checked_mul(a_step)?.checked_add(b.checked_mul(b_step)?);
+    // A claw move too far to count goes past the prize
+    if reach(ax, bx) != Some(px) || reach(ay, by) != Some(py) { // check solution
+        return Ok(None);
+    }
+    reach(a_cost, b_cost).map(Some).ok_or_else(too_large)
+}
+
+/// The claw machines, and the prices and prize offset to play them with.
+pub struct Arcade {
+    machines: Vec<[u64; 6]>,
+    a_cost: u64,
+    b_cost: u64,
+    prize_offset: u64,  // Added to prize coordinates in part 2
+}
+
+impl Solution for Arcade {
+    fn parse(input: &str, params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let lines = [
+            ("Button A: X+<n>, Y+<n>", Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap()),
+            ("Button B: X+<n>, Y+<n>", Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap()),
+            ("Prize: X=<n>, Y=<n>", Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap()),
+        ];
+
+        // Every block between blank lines must be a whole machine, so that
+        // none is skipped over
+        let mut machines: Vec<[u64; 6]> = vec![];
+        for block in src.sections(input) {
+            let mut block_lines = block.lines();
+            let mut machine = [0; 6];
+            for (nums, (expected, re)) in machine.chunks_mut(2).zip(lines.iter()) {
+                let line = block_lines.next().unwrap_or(&block[block.len()..]);
+                let caps = re.captures(line).ok_or_else(|| src.error(line, format!("expected {:?}", expected)))?;
+                nums[0] = src.num(caps.get(1).unwrap().as_str())?;
+                nums[1] = src.num(caps.get(2).unwrap().as_str())?;
+            }
+            if let Some(extra) = block_lines.next() {
+                return Err(src.error(extra, "expected a blank line after the prize"));
+            }
+            machines.push(machine);
+        }
+        if machines.is_empty() {
+            return Err(src.error(input, "expected \"Button A: ...\", \"Button B: ...\" and \"Prize: ...\""));
+        }
+        Ok(Self {
+            machines,
+            a_cost: params.get_nonneg(src, "a_cost", 3)?,
+            b_cost: params.get_nonneg(src, "b_cost", 1)?,
+            prize_offset: params.get_nonneg(src, "prize_offset", 10000000000000)?,
+        })
+    }
+
+    fn param_names() -> &'static [&'static str] {
+        &["a_cost", "b_cost", "prize_offset"]
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(self.machines
+            .iter()
+            .map(|[ax, ay, bx, by, px, py]| solve(*ax, *ay, *bx, *by, *px, *py, self.a_cost, self.b_cost))
+            .filter_map(Result::transpose)
+            .try_fold(0u64, |sum, tokens| sum.checked_add(tokens?).ok_or_else(too_large))?
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        let p_add = self.prize_offset;
+        Ok(self.machines
+            .iter()
+            .map(|[ax, ay, bx, by, px, py]| {
+                let (px, py) = p_add.checked_add(*px).zip(p_add.checked_add(*py)).ok_or_else(too_large)?;
+                solve(*ax, *ay, *bx, *by, px, py, self.a_cost, self.b_cost)
+            })
+            .filter_map(Result::transpose)
+            .try_fold(0u64, |sum, tokens| sum.checked_add(tokens?).ok_or_else(too_large))?
+            .into())
+    }
+}
+
+/// Parse the claw machines.
+pub fn parse(input: &str, params: &Params) -> Result<Arcade, ParseError> {
+    Arcade::from_input(input, params)
+}
+
+/// The fewest tokens that win every winnable prize.
+pub fn solve_part1(arcade: &Arcade) -> Result<Answer, SolveError> {
+    arcade.part1()
+}
+
+/// The fewest tokens that win every winnable prize, once `prize_offset` is added to the prizes.
+pub fn solve_part2(arcade: &Arcade) -> Result<Answer, SolveError> {
+    arcade.part2()
+}
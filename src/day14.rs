@@ -0,0 +1,241 @@
+//! Day 14: Restroom Redoubt.
+
+use std::collections::HashSet;
+
+use tracing::debug;
+
+use crate::{
+    parallel, Answer, Color, Cycle, Frame, Frames, Outcome, Palette, Params, ParseError, Pos,
+    Simulation, Solution, SolveError, Source,
+};
+
+fn parse_coord(src: Source, s: &str) -> Result<Pos, ParseError> {
+    let (x, y) = src.split_once(s, ",")?;
+    Ok(Pos { y: src.num(y)?, x: src.num(x)? })
+}
+
+#[derive(Debug)]
+struct Area {
+    tl: Pos,
+    br: Pos,
+}
+
+impl Area {
+    fn from_bounds(bounds: Pos) -> Self {
+        Self { tl: Pos { y: 0, x: 0 }, br: bounds }
+    }
+
+    fn quadrants(&self) -> [Self; 4] {
+        let size = Pos {
+            y: (self.br.y - self.tl.y) / 2,
+            x: (self.br.x - self.tl.x) / 2,
+        };
+        // +---,+---,
+        // | 1 || 2 |
+        // `---+`---+
+        // +---,+---,
+        // | 3 || 4 |
+        // `---+`---+
+        [
+            Self {
+                tl: self.tl,
+                br: self.tl + size,
+            },
+            Self {
+                tl: Pos { y: self.tl.y, x: self.br.x - size.x },
+                br: Pos { y: self.tl.y + size.y, x: self.br.x },
+            },
+            Self {
+                tl: Pos { y: self.br.y - size.y, x: self.tl.x },
+                br: Pos { y: self.br.y, x: self.tl.x + size.x },
+            },
+            Self {
+                tl: self.br - size,
+                br: self.br,
+            },
+        ]
+    }
+
+    fn contains(&self, p: Pos) -> bool {
+        p.y >= self.tl.y && p.y < self.br.y && p.x >= self.tl.x && p.x < self.br.x
+    }
+}
+
+/// A robot's position and velocity, in tiles per second.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Robot {
+    pub pos: Pos,
+    pub vel: Pos,
+}
+
+impl Robot {
+    /// Parse a robot in a room of size `bounds`. Its velocity is wrapped
+    /// into the room too, which moves it to the same places without
+    /// overflowing.
+    fn parse(src: Source, line: &str, bounds: Pos) -> Result<Self, ParseError> {
+        let (pos_s, vel_s) = src.split_once(line, " ")?;
+        let pos = parse_coord(src, src.strip_prefix(pos_s, "p=")?)?;
+        let vel = parse_coord(src, src.strip_prefix(vel_s, "v=")?)?;
+        if !Area::from_bounds(bounds).contains(pos) {
+            return Err(src.error(pos_s, format!("the robot is outside the {}x{} room", bounds.x, bounds.y)));
+        }
+        Ok(Self { pos, vel: vel.wrap(bounds) })
+    }
+
+    /// The robot a second later, wrapping around the edges of a room of size `bounds`.
+    pub fn jump(&self, bounds: Pos) -> Self {
+        let pos = (self.pos + self.vel).wrap(bounds);
+        Self { pos, ..*self }
+    }
+
+    /// The robot after `seconds` seconds, as if it jumped that many times.
+    pub fn after(&self, bounds: Pos, seconds: usize) -> Self {
+        let along = |pos: i64, vel: i64, size: i64| {
+            (pos as i128 + vel as i128 * seconds as i128).rem_euclid(size as i128) as i64
+        };
+        let pos = Pos { y: along(self.pos.y, self.vel.y, bounds.y), x: along(self.pos.x, self.vel.x, bounds.x) };
+        Self { pos, ..*self }
+    }
+}
+
+/// The product of the numbers of robots in each quadrant of the room.
+pub fn safety_factor(bounds: Pos, robots: Vec<Robot>) -> usize {
+    Area::from_bounds(bounds)
+        .quadrants()
+        .map(|quadrant| robots.iter().filter(|r| quadrant.contains(r.pos)).count())
+        .iter()
+        .product()
+}
+
+fn is_cluster(positions: &HashSet<Pos>, pos: Pos) -> bool {
+    pos.surrounding().all(|p| positions.contains(&p))
+}
+
+/// Whether the robots form a picture, taken to be when some robot is
+/// surrounded by others on all 8 sides.
+pub fn has_xmas_tree(robots: &[Robot]) -> bool {
+    let positions: HashSet<Pos> = robots.iter().map(|r| r.pos).collect();
+    positions.iter().any(|pos| is_cluster(&positions, *pos))
+}
+
+fn frame(robots: &[Robot], bounds: Pos, seconds: usize) -> Frame {
+    let mut frame = Frame::filled(bounds, '.');
+    for robot in robots.iter() {
+        frame.draw(robot.pos, '#', Color::Green);
+    }
+    frame.with_caption(format!("After {} seconds", seconds))
+}
+
+/// The robots, the size of the room, and how long part 1 runs for.
+pub struct Robots {
+    robots: Vec<Robot>,
+    bounds: Pos,
+    seconds: usize,
+}
+
+impl Robots {
+    pub fn robots(&self) -> &[Robot] {
+        &self.robots
+    }
+
+    pub fn bounds(&self) -> Pos {
+        self.bounds
+    }
+
+    fn jump_all(&self, robots: Vec<Robot>) -> Vec<Robot> {
+        robots.into_iter().map(|r| r.jump(self.bounds)).collect()
+    }
+
+    /// The robots jumping once a second, forever.
+    pub fn simulation(&self) -> Simulation<Vec<Robot>, impl Fn(&Vec<Robot>) -> Option<Vec<Robot>> + use<'_>> {
+        Simulation::new(self.robots.to_vec(), |robots| Some(robots.iter().map(|r| r.jump(self.bounds)).collect()))
+    }
+
+    /// Every robot is back where it started after this many seconds. That is
+    /// at most the lcm of the room's sides, but can be less, e.g. if no robot
+    /// moves across the room. Jumps can be undone, so the robots' cycle starts
+    /// from where they are now.
+    fn period(&self) -> usize {
+        match self.simulation().brent().run() {
+            Outcome::Cycle(Cycle { period, .. }) => period,
+            _ => unreachable!("robots jump forever"),
+        }
+    }
+}
+
+impl Solution for Robots {
+    fn parse(input: &str, params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let bounds = Pos { y: params.get("height", 103), x: params.get("width", 101) };
+        if bounds.y <= 0 || bounds.x <= 0 {
+            return Err(src.error_at_end(format!("the room must have a positive size, not {}x{}", bounds.x, bounds.y)));
+        }
+        let robots: Vec<Robot> = input
+            .lines()
+            .map(|line| Robot::parse(src, line, bounds))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { robots, bounds, seconds: params.get_nonneg(src, "seconds", 100)? })
+    }
+
+    fn param_names() -> &'static [&'static str] {
+        &["height", "width", "seconds"]
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let mut p1_robots = self.robots.to_vec();
+        for _ in 0..self.seconds {
+            p1_robots = self.jump_all(p1_robots);
+        }
+        Ok(safety_factor(self.bounds, p1_robots).into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        // Part 2: Look for when robots line up symmetrically around the vertical center line.
+        // Robots repeat their positions after a period, so give up then
+        let period = self.period();
+        let seconds = parallel::find_first(0..period, |&seconds| {
+            let robots: Vec<Robot> = self.robots.iter().map(|r| r.after(self.bounds, seconds)).collect();
+            has_xmas_tree(&robots)
+        })
+        .ok_or_else(|| SolveError::new("the robots never form a Christmas tree"))?;
+        debug!(seconds, period, "found a Christmas tree");
+        Ok(seconds.into())
+    }
+
+    fn visualize(&self, part: u8) -> Option<Frames<'_>> {
+        let mut robots = Some(self.robots.to_vec());
+        let last = match part {
+            1 => self.seconds,
+            2 => self.period() - 1,
+            _ => return None,
+        };
+        // Part 2 stops at the Christmas tree
+        Some(Box::new((0..=last).map_while(move |seconds| {
+            let current = robots.take()?;
+            let frame = frame(&current, self.bounds, seconds);
+            if part == 1 || !has_xmas_tree(&current) {
+                robots = Some(self.jump_all(current));
+            }
+            Some(frame)
+        })))
+    }
+
+    fn palette(&self) -> Palette {
+        Palette { background: [8, 16, 40], colors: &[(Color::Green, [40, 190, 70])], ..Palette::DEFAULT }
+    }
+}
+
+/// Parse the robots' positions and velocities.
+pub fn parse(input: &str, params: &Params) -> Result<Robots, ParseError> {
+    Robots::from_input(input, params)
+}
+
+/// The safety factor after `seconds` (100) seconds.
+pub fn solve_part1(robots: &Robots) -> Result<Answer, SolveError> {
+    robots.part1()
+}
+
+/// The first second at which the robots form a Christmas tree.
+pub fn solve_part2(robots: &Robots) -> Result<Answer, SolveError> {
+    robots.part2()
+}
@@ -0,0 +1,428 @@
+//! Day 15: Warehouse Woes.
+
+use std::iter;
+
+use tracing::trace;
+
+use crate::{
+    Answer, Color, Dir, Event, Events, Frame, Frames, Grid, Palette, Params, ParseError, Pos,
+    Solution, SolveError, Source,
+};
+
+fn gps(pos: Pos) -> i64 {
+    pos.y * 100 + pos.x
+}
+
+/// What is on a tile of the warehouse floor. Part 1 has `Box`es, and part 2
+/// boxes twice as wide, as a `BoxLeft` and a `BoxRight`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+
+impl Tile {
+    fn render(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Wall => '#',
+            Self::Box => 'O',
+            Self::BoxLeft => '[',
+            Self::BoxRight => ']',
+        }
+    }
+
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Empty => None,
+            Self::Wall => Some(Color::Gray),
+            Self::Box | Self::BoxLeft | Self::BoxRight => Some(Color::Yellow),
+        }
+    }
+}
+
+fn frame(map: &Grid<Tile>, robot: Pos) -> Frame {
+    let mut frame = Frame::new(map, |_, tile| tile.render());
+    for (pos, tile) in map.iter() {
+        if let Some(color) = tile.color() {
+            frame.highlight(pos, color);
+        }
+    }
+    frame.draw(robot, '@', Color::Red);
+    frame
+}
+
+/// The warehouse in part 1, and where the robot is.
+#[derive(Clone, Debug)]
+pub struct Part1Map {
+    map: Grid<Tile>,
+    robot: Pos,
+}
+
+impl Part1Map {
+    pub fn tiles(&self) -> &Grid<Tile> {
+        &self.map
+    }
+
+    pub fn robot(&self) -> Pos {
+        self.robot
+    }
+
+    fn parse(src: Source, map_s: &str) -> Result<Self, ParseError> {
+        let mut robot = None;
+        let map = Grid::try_parse(map_s.split("\n"), |pos, byte| match byte {
+            b'#' => Some(Tile::Wall),
+            b'O' => Some(Tile::Box),
+            b'@' => { robot = Some(pos); Some(Tile::Empty) },
+            b'.' => Some(Tile::Empty),
+            _ => None,
+        })?;
+        let robot = robot.ok_or_else(|| src.error(map_s, "no robot ('@') found"))?;
+        // Moves never check the edge of the map, so the walls must go all around
+        let size = map.size();
+        let edge = map.positions().find(|pos| {
+            (pos.y == 0 || pos.x == 0 || pos.y == size.y - 1 || pos.x == size.x - 1) && map[*pos] != Tile::Wall
+        });
+        if let Some(pos) = edge {
+            let line = map_s.split("\n").nth(pos.y as usize).unwrap();
+            return Err(src.error(&line[pos.x as usize..], "expected a wall ('#') around the edge of the map"));
+        }
+        Ok(Self { map, robot })
+    }
+
+    /// Whether whatever is at `pos` can move one step in `dir`.
+    pub fn can_move(&self, pos: Pos, dir: Dir) -> bool {
+        let nbor = pos + dir.pos();
+        match self.map[nbor] {
+            Tile::Box => self.can_move(nbor, dir),
+            Tile::Wall => false,
+            _ => true,
+        }
+    }
+
+    fn push_boxes(&mut self, pos: Pos, dir: Dir, pushed: &mut Vec<Pos>) {
+        if self.map[pos] != Tile::Box {
+            assert_ne!(self.map[pos], Tile::Wall);
+            return;
+        }
+        let nbor = pos + dir.pos();
+        self.push_boxes(nbor, dir, pushed);
+        assert_eq!(self.map[nbor], Tile::Empty);
+        trace!(?pos, ?dir, "push box");
+        self.map[pos] = Tile::Empty;
+        self.map[nbor] = Tile::Box;
+        pushed.push(pos);
+    }
+
+    /// Try to move the robot one step, pushing any boxes in the way.
+    pub fn move_robot(&mut self, dir: Dir) {
+        self.try_move(dir);
+    }
+
+    /// Move the robot one step if it can, returning where the boxes it
+    /// pushed were, or `None` if it is blocked.
+    pub fn try_move(&mut self, dir: Dir) -> Option<Vec<Pos>> {
+        if !self.can_move(self.robot, dir) {
+            trace!(robot = ?self.robot, ?dir, "blocked");
+            return None;
+        }
+        let nbor = self.robot + dir.pos();
+        let mut pushed = vec![];
+        self.push_boxes(nbor, dir, &mut pushed);
+        assert_eq!(self.map[nbor], Tile::Empty);
+        self.robot = nbor;
+        Some(pushed)
+    }
+
+    /// The sum of the GPS coordinates of the boxes.
+    pub fn boxes_gps(&self) -> i64 {
+        self.map.iter().filter(|(_, tile)| **tile == Tile::Box).map(|(p, _)| gps(p)).sum()
+    }
+}
+
+/// The warehouse scaled up to twice as wide for part 2, and where the robot is.
+#[derive(Clone, Debug)]
+pub struct Part2Map {
+    map: Grid<Tile>,
+    robot: Pos,
+}
+
+impl Part2Map {
+    pub fn tiles(&self) -> &Grid<Tile> {
+        &self.map
+    }
+
+    pub fn robot(&self) -> Pos {
+        self.robot
+    }
+
+    /// Scale up a part 1 warehouse.
+    pub fn extend(p1map: &Part1Map) -> Self {
+        let size = p1map.map.size();
+        let map = Grid::from_fn(Pos { y: size.y, x: size.x * 2 }, |p| {
+            match (p1map.map[Pos { y: p.y, x: p.x / 2 }], p.x % 2) {
+                (Tile::Wall, _) => Tile::Wall,
+                (Tile::Box, 0) => Tile::BoxLeft,
+                (Tile::Box, _) => Tile::BoxRight,
+                _ => Tile::Empty,
+            }
+        });
+        let robot = Pos { y: p1map.robot.y, x: p1map.robot.x * 2 };
+        Self { map, robot }
+    }
+
+    fn is_box_left(&self, pos: Pos) -> bool {
+        self.map[pos] == Tile::BoxLeft
+    }
+
+    fn is_box_right(&self, pos: Pos) -> bool {
+        self.map[pos] == Tile::BoxRight
+    }
+
+    fn is_wall(&self, pos: Pos) -> bool {
+        self.map[pos] == Tile::Wall
+    }
+
+    /// Whether whatever is at `pos` can move one step in `dir`.
+    pub fn can_move(&self, pos: Pos, dir: Dir) -> bool {
+        let nbor = pos + dir.pos();
+        match (dir, self.is_box_left(nbor), self.is_box_right(nbor)) {
+            (Dir::Up, true, _) => {
+                assert!(self.is_box_right(nbor + Dir::Right.pos()));
+                self.can_move(nbor, dir) &&
+                    self.can_move(nbor + Dir::Right.pos(), dir)
+            },
+            (Dir::Up, _, true) => {
+                assert!(self.is_box_left(nbor + Dir::Left.pos()));
+                self.can_move(nbor + Dir::Left.pos(), dir) &&
+                    self.can_move(nbor, dir)
+            },
+            (Dir::Down, true, _) => {
+                assert!(self.is_box_right(nbor + Dir::Right.pos()));
+                self.can_move(nbor, dir) &&
+                    self.can_move(nbor + Dir::Right.pos(), dir)
+            },
+            (Dir::Down, _, true) => {
+                assert!(self.is_box_left(nbor + Dir::Left.pos()));
+                self.can_move(nbor + Dir::Left.pos(), dir) &&
+                    self.can_move(nbor, dir)
+            },
+            (Dir::Right, true, _) => {
+                assert!(self.is_box_right(nbor + dir.pos()));
+                self.can_move(nbor + dir.pos(), dir)
+            },
+            (Dir::Left, _, true) => {
+                assert!(self.is_box_left(nbor + dir.pos()));
+                self.can_move(nbor + dir.pos(), dir)
+            },
+            (Dir::Right, false, _) => {
+                assert!(self.is_wall(nbor) || !self.is_box_right(nbor + dir.pos()));
+                !self.is_wall(nbor)
+            },
+            (Dir::Left, _, false) => {
+                assert!(self.is_wall(nbor) || !self.is_box_left(nbor + dir.pos()));
+                !self.is_wall(nbor)
+            },
+            (_, false, false) => {
+                !self.is_wall(nbor)
+            },
+        }
+    }
+
+    fn push_box_lefts(&mut self, pos: Pos, dir: Dir, pushed: &mut Vec<Pos>) {
+        assert!(!self.is_wall(pos));
+        assert!(!self.is_box_right(pos));
+        if !self.is_box_left(pos) { // nothing in the way
+            return;
+        }
+        // There is a box_left at pos and we need to push it
+        let nbor = pos + dir.pos();
+        match dir {
+            Dir::Left => self.push_boxes(nbor, dir, pushed),
+            Dir::Right => self.push_boxes(nbor + dir.pos(), dir, pushed),
+            Dir::Up | Dir::Down => {
+                self.push_boxes(nbor, dir, pushed);
+                self.push_boxes(nbor + Dir::Right.pos(), dir, pushed);
+            },
+        };
+        assert!(!self.is_box_left(nbor) && !self.is_box_right(nbor + Dir::Right.pos()));
+        assert!(!self.is_wall(nbor) && !self.is_wall(nbor + Dir::Right.pos()));
+        trace!(?pos, ?dir, "push wide box");
+        self.map[pos] = Tile::Empty;
+        self.map[pos + Dir::Right.pos()] = Tile::Empty;
+        self.map[nbor] = Tile::BoxLeft;
+        self.map[nbor + Dir::Right.pos()] = Tile::BoxRight;
+        pushed.push(pos);
+    }
+
+    fn push_boxes(&mut self, pos: Pos, dir: Dir, pushed: &mut Vec<Pos>) {
+        if dir == Dir::Right { // never try to push a box_right rightwards
+            assert!(!self.is_box_right(pos));
+        } else if dir == Dir::Left { // or a box_left leftwards
+            assert!(!self.is_box_left(pos));
+        }
+
+        let left_pos = match self.is_box_right(pos) {
+            true => pos + Dir::Left.pos(),
+            false => pos,
+        };
+        if self.is_box_left(left_pos) {
+            self.push_box_lefts(left_pos, dir, pushed);
+        }
+    }
+
+    /// Try to move the robot one step, pushing any boxes in the way.
+    pub fn move_robot(&mut self, dir: Dir) {
+        self.try_move(dir);
+    }
+
+    /// Move the robot one step if it can, returning where the left halves of
+    /// the boxes it pushed were, or `None` if it is blocked.
+    pub fn try_move(&mut self, dir: Dir) -> Option<Vec<Pos>> {
+        if !self.can_move(self.robot, dir) {
+            trace!(robot = ?self.robot, ?dir, "blocked");
+            return None;
+        }
+        let nbor = self.robot + dir.pos();
+        let mut pushed = vec![];
+        self.push_boxes(nbor, dir, &mut pushed);
+        assert_eq!(self.map[nbor], Tile::Empty);
+        assert!(self.boxes_paired());
+        self.robot = nbor;
+        Some(pushed)
+    }
+
+    fn boxes_paired(&self) -> bool {
+        self.map.iter().all(|(pos, tile)| match tile {
+            Tile::BoxLeft => self.is_box_right(pos + Dir::Right.pos()),
+            Tile::BoxRight => self.is_box_left(pos + Dir::Left.pos()),
+            _ => true,
+        })
+    }
+
+    /// The sum of the GPS coordinates of the boxes.
+    pub fn boxes_gps(&self) -> i64 {
+        self.map.iter().filter(|(_, tile)| **tile == Tile::BoxLeft).map(|(p, _)| gps(p)).sum()
+    }
+}
+
+/// The warehouse as it starts, and the robot's moves.
+pub struct Warehouse {
+    map: Part1Map,
+    moves: Vec<Dir>,
+}
+
+impl Warehouse {
+    pub fn map(&self) -> &Part1Map {
+        &self.map
+    }
+
+    pub fn moves(&self) -> &[Dir] {
+        &self.moves
+    }
+}
+
+impl Solution for Warehouse {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let (map_s, moves_s) = src.two_sections(input)?;
+        let map = Part1Map::parse(src, map_s)?;
+        let moves: Vec<Dir> = moves_s
+            .char_indices()
+            .filter(|(_, c)| *c != '\n')
+            .map(|(i, c)| Dir::parse(c).ok_or_else(|| {
+                src.error(&moves_s[i..i + c.len_utf8()], "expected one of '^', '>', 'v' or '<'")
+            }))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { map, moves })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let mut part1 = self.map.clone();
+        for dir in self.moves.iter() {
+            part1.move_robot(*dir);
+        }
+        Ok(part1.boxes_gps().into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        let mut part2 = Part2Map::extend(&self.map);
+        for dir in self.moves.iter() {
+            part2.move_robot(*dir);
+        }
+        Ok(part2.boxes_gps().into())
+    }
+
+    fn visualize(&self, part: u8) -> Option<Frames<'_>> {
+        let caption = |i: usize, dir: &Dir| format!("Move {}/{}: {}", i + 1, self.moves.len(), dir.arrow());
+        match part {
+            1 => {
+                let mut map = self.map.clone();
+                let first = frame(&map.map, map.robot).with_caption("Initial state");
+                Some(Box::new(iter::once(first).chain(self.moves.iter().enumerate().map(move |(i, dir)| {
+                    map.move_robot(*dir);
+                    frame(&map.map, map.robot).with_caption(caption(i, dir))
+                }))))
+            },
+            2 => {
+                let mut map = Part2Map::extend(&self.map);
+                let first = frame(&map.map, map.robot).with_caption("Initial state");
+                Some(Box::new(iter::once(first).chain(self.moves.iter().enumerate().map(move |(i, dir)| {
+                    map.move_robot(*dir);
+                    frame(&map.map, map.robot).with_caption(caption(i, dir))
+                }))))
+            },
+            _ => None,
+        }
+    }
+
+    fn trace(&self, part: u8) -> Option<Events<'_>> {
+        let event = |dir: &Dir, robot: Pos, pushed: Option<Vec<Pos>>| match pushed {
+            Some(pushed) => Event::new("move")
+                .with("dir", dir.arrow())
+                .with_pos("robot", robot)
+                .with_positions("pushed", &pushed),
+            None => Event::new("blocked").with("dir", dir.arrow()).with_pos("robot", robot),
+        };
+        match part {
+            1 => {
+                let mut map = self.map.clone();
+                Some(Box::new(self.moves.iter().map(move |dir| {
+                    let pushed = map.try_move(*dir);
+                    event(dir, map.robot, pushed)
+                })))
+            },
+            2 => {
+                let mut map = Part2Map::extend(&self.map);
+                Some(Box::new(self.moves.iter().map(move |dir| {
+                    let pushed = map.try_move(*dir);
+                    event(dir, map.robot, pushed)
+                })))
+            },
+            _ => None,
+        }
+    }
+
+    fn palette(&self) -> Palette {
+        let colors = &[(Color::Gray, [90, 90, 100]), (Color::Yellow, [180, 120, 60]), (Color::Red, [240, 80, 60])];
+        Palette { colors, ..Palette::DEFAULT }
+    }
+}
+
+/// Parse the warehouse map and the robot's moves.
+pub fn parse(input: &str, params: &Params) -> Result<Warehouse, ParseError> {
+    Warehouse::from_input(input, params)
+}
+
+/// The sum of the boxes' GPS coordinates after all the moves.
+pub fn solve_part1(warehouse: &Warehouse) -> Result<Answer, SolveError> {
+    warehouse.part1()
+}
+
+/// The same, in the warehouse scaled up to twice as wide.
+pub fn solve_part2(warehouse: &Warehouse) -> Result<Answer, SolveError> {
+    warehouse.part2()
+}
@@ -0,0 +1,218 @@
+//! Day 16: Reindeer Maze.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::iter;
+
+use itertools::Itertools;
+use tracing::{debug, trace};
+
+use crate::{
+    Answer, Color, Dir, Frame, Frames, Grid, Palette, Params, ParseError, Pos, Solution, SolveError,
+    Source,
+};
+
+pub type Score = usize;
+
+/// Where the reindeer is, and which way it faces.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct State {
+    pub pos: Pos,  // Where are we
+    pub dir: Dir,  // Which direction are we facing upon entering this location
+}
+
+impl State {
+    /// The states one move away, turning first or not, and what each costs.
+    pub fn adjacents(&self, move_cost: Score, turn_cost: Score) -> impl Iterator<Item = (Self, Score)> {
+        let cw_dir = self.dir.turn_right();
+        let ccw_dir = self.dir.turn_left();
+        [
+            (State { pos: self.pos + self.dir.pos(), dir: self.dir }, move_cost), // Move one space
+            (State { pos: self.pos + cw_dir.pos(), dir: cw_dir }, turn_cost + move_cost), // Turn CW + move
+            (State { pos: self.pos + ccw_dir.pos(), dir: ccw_dir }, turn_cost + move_cost), // Turn CCw + move
+        ].into_iter()
+    }
+}
+
+/// The maze, with `true` for open tiles, its start and end, and the cost of
+/// moving and turning.
+#[derive(Debug)]
+pub struct Maze {
+    spaces: Grid<bool>,
+    start: Pos,
+    end: Pos,
+    move_cost: Score,
+    turn_cost: Score,
+}
+
+impl Maze {
+    pub fn end(&self) -> Pos {
+        self.end
+    }
+
+    fn parse_map(src: Source, move_cost: Score, turn_cost: Score) -> Result<Self, ParseError> {
+        let mut start = None;
+        let mut end = None;
+        let spaces = Grid::try_parse(src.lines(), |pos, byte| {
+            match byte {
+                b'S' => start = Some(pos),
+                b'E' => end = Some(pos),
+                b'.' | b'#' => (),
+                _ => return None,
+            }
+            Some(byte != b'#')  // wall
+        })?;
+        let start = start.ok_or_else(|| src.error_at_end("no start ('S') found"))?;
+        let end = end.ok_or_else(|| src.error_at_end("no end ('E') found"))?;
+        Ok(Self { spaces, start, end, move_cost, turn_cost })
+    }
+
+    /// The moves from `player` that don't run into a wall.
+    pub fn next_moves(&self, player: State) -> impl Iterator<Item = (State, Score)> + use<'_> {
+        player.adjacents(self.move_cost, self.turn_cost).filter(|(p, _)| self.spaces.get(p.pos) == Some(&true))
+    }
+
+    /// The lowest score from `start` to `end`, and every state on any path
+    /// with that score.
+    pub fn shortest_paths(&self, start: State, end: Pos) -> Option<(Score, HashSet<State>)> {
+        let mut min_scores: HashMap<State, Score> = HashMap::new();
+        let mut next_moves: BinaryHeap<Reverse<(Score, State)>> = BinaryHeap::new();
+        next_moves.push(Reverse((0, start)));
+        let mut prev_states: HashMap<State, HashSet<State>> = HashMap::new();
+        let mut end_state = None;
+        while let Some(Reverse((score, current))) = next_moves.pop() {
+            trace!(score, pos = ?current.pos, dir = ?current.dir, "pop");
+            if current.pos == end {
+                debug!(score, visited = min_scores.len(), "reached the end");
+                end_state = Some((current, score));
+                break;
+            }
+            for (next, d_score) in self.next_moves(current) {
+                let old_score = *min_scores.get(&next).unwrap_or(&Score::MAX);
+                let new_score = score + d_score;
+                if new_score < old_score {
+                    min_scores.insert(next, new_score);
+                    next_moves.push(Reverse((new_score, next)));
+                    prev_states.insert(next, HashSet::new());
+                }
+                if new_score <= old_score {
+                    prev_states.entry(next).and_modify(|set| { set.insert(current); });
+                }
+            }
+        }
+
+        // Walk back from the end, visiting each state once however many best
+        // paths it is on
+        let (end_state, score) = end_state?;
+        let mut on_paths: HashSet<State> = HashSet::from([end_state]);
+        let mut todo = vec![end_state];
+        while let Some(state) = todo.pop() {
+            if state == start {
+                continue;
+            }
+            for prev in prev_states[&state].iter() {
+                if on_paths.insert(*prev) {
+                    todo.push(*prev);
+                }
+            }
+        }
+        debug!(states = on_paths.len(), "walked back along the best paths");
+        Some((score, on_paths))
+    }
+
+    /// The reindeer at the start, facing east.
+    pub fn start_state(&self) -> State {
+        State { pos: self.start, dir: Dir::Right }
+    }
+
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(&self.spaces, |_, space| if *space { '.' } else { '#' });
+        for (pos, space) in self.spaces.iter() {
+            if !space {
+                frame.highlight(pos, Color::Gray);
+            }
+        }
+        frame.draw(self.start, 'S', Color::Cyan);
+        frame.draw(self.end, 'E', Color::Cyan);
+        frame
+    }
+}
+
+fn no_path() -> SolveError {
+    SolveError::new("there is no way from the start to the end")
+}
+
+impl Solution for Maze {
+    fn parse(input: &str, params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let move_cost = params.get_nonneg(src, "move_cost", 1)?;
+        let turn_cost = params.get_nonneg(src, "turn_cost", 1000)?;
+        Self::parse_map(src, move_cost, turn_cost)
+    }
+
+    fn param_names() -> &'static [&'static str] {
+        &["move_cost", "turn_cost"]
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let (min_score, _) = self.shortest_paths(self.start_state(), self.end).ok_or_else(no_path)?;
+        Ok(min_score.into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        let (_, on_paths) = self.shortest_paths(self.start_state(), self.end).ok_or_else(no_path)?;
+        Ok(on_paths.iter().map(|state| state.pos).unique().count().into())
+    }
+
+    fn visualize(&self, part: u8) -> Option<Frames<'_>> {
+        if part != 1 && part != 2 {
+            return None;
+        }
+        let Some((score, on_paths)) = self.shortest_paths(self.start_state(), self.end) else {
+            return Some(Box::new(iter::once(self.frame().with_caption(no_path().message))));
+        };
+        // Spread out along the best paths from the start, one move per frame
+        let mut done: HashSet<State> = HashSet::new();
+        let mut tiles: HashSet<Pos> = HashSet::new();
+        let mut front: HashSet<State> = HashSet::from([self.start_state()]);
+        Some(Box::new(iter::from_fn(move || {
+            if front.is_empty() {
+                return None;
+            }
+            done.extend(front.iter().copied());
+            tiles.extend(front.iter().map(|state| state.pos));
+            let mut frame = self.frame();
+            for pos in tiles.iter().filter(|pos| **pos != self.start && **pos != self.end) {
+                frame.draw(*pos, 'O', Color::Green);
+            }
+            for state in front.iter() {
+                frame.draw(state.pos, state.dir.arrow(), Color::Red);
+            }
+            front = front.iter()
+                .flat_map(|state| self.next_moves(*state))
+                .map(|(next, _)| next)
+                .filter(|next| on_paths.contains(next) && !done.contains(next))
+                .collect();
+            Some(frame.with_caption(format!("Score {}, {} tiles on a best path", score, tiles.len())))
+        })))
+    }
+
+    fn palette(&self) -> Palette {
+        Palette { colors: &[(Color::Gray, [50, 60, 80]), (Color::Green, [90, 220, 120])], ..Palette::DEFAULT }
+    }
+}
+
+/// Parse the maze.
+pub fn parse(input: &str, params: &Params) -> Result<Maze, ParseError> {
+    Maze::from_input(input, params)
+}
+
+/// The lowest score a reindeer could get from start to end.
+pub fn solve_part1(maze: &Maze) -> Result<Answer, SolveError> {
+    maze.part1()
+}
+
+/// The number of tiles on at least one of the best paths.
+pub fn solve_part2(maze: &Maze) -> Result<Answer, SolveError> {
+    maze.part2()
+}
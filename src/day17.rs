@@ -0,0 +1,429 @@
+//! Day 17: Chronospatial Computer.
+
+use std::hash::{Hash, Hasher};
+
+use regex::Regex;
+use tracing::{debug, trace};
+
+use crate::{
+    parallel, Answer, Color, Cycle, Event, Events, Frame, Frames, Outcome, Params, ParseError, Pos,
+    Simulation, Solution, SolveError, Source,
+};
+
+/// One instruction, with its operand.
+#[derive(Debug)]
+pub enum Instruction {
+    Adv(i64),
+    Bxl(i64),
+    Bst(i64),
+    Jnz(i64),
+    Bxc(i64),
+    Out(i64),
+    Bdv(i64),
+    Cdv(i64),
+}
+
+use Instruction::*;
+
+impl Instruction {
+    /// The instruction's name and operand.
+    pub fn mnemonic(&self) -> (&'static str, i64) {
+        match *self {
+            Adv(arg) => ("adv", arg),
+            Bxl(arg) => ("bxl", arg),
+            Bst(arg) => ("bst", arg),
+            Jnz(arg) => ("jnz", arg),
+            Bxc(arg) => ("bxc", arg),
+            Out(arg) => ("out", arg),
+            Bdv(arg) => ("bdv", arg),
+            Cdv(arg) => ("cdv", arg),
+        }
+    }
+
+    pub fn parse(opcode: i64, operand: i64) -> Self {
+        match opcode {
+            0 => Adv(operand),
+            1 => Bxl(operand),
+            2 => Bst(operand),
+            3 => Jnz(operand),
+            4 => Bxc(operand),
+            5 => Out(operand),
+            6 => Bdv(operand),
+            7 => Cdv(operand),
+            _ => panic!("Invalid opcode"),
+        }
+    }
+}
+
+/// Programs running longer than this are assumed never to halt.
+const MAX_STEPS: usize = 100_000;
+
+/// Part 2 finds 3 more bits of A for each value in the program, starting from
+/// 10 bits, so longer programs would need more bits than A has.
+pub(crate) const MAX_PART2_LEN: usize = 19;
+
+/// The state of the computer: its registers, instruction pointer, program
+/// and what it has output so far.
+#[derive(Clone, Debug)]
+pub struct Machine {
+    a: i64,
+    b: i64,
+    c: i64,
+    ip: usize,
+    program: Vec<i64>,
+    output: Vec<i64>,
+}
+
+/// Machines are equal when they will do the same from here on, whatever
+/// they have output so far.
+impl PartialEq for Machine {
+    fn eq(&self, other: &Self) -> bool {
+        (self.a, self.b, self.c, self.ip) == (other.a, other.b, other.c, other.ip) && self.program == other.program
+    }
+}
+
+impl Eq for Machine {}
+
+impl Hash for Machine {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.a, self.b, self.c, self.ip).hash(state);
+    }
+}
+
+impl Machine {
+    /// The machine after running the instruction at the instruction pointer.
+    pub fn execute_one(&self) -> Self {
+        let instr = Instruction::parse(self.program[self.ip], self.program[self.ip + 1]);
+        let mut ip = self.ip + 2;
+        trace!(ip = self.ip, ?instr, a = self.a, b = self.b, c = self.c, "execute");
+        let (mut a, mut b, mut c) = (self.a, self.b, self.c);
+        let mut output = self.output.to_vec();
+
+        let combo = |arg: i64| match arg {
+            0 | 1 | 2 | 3 => arg,
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => unreachable!("combo operand 7 is rejected when parsing"),
+        };
+        // Shifting by 64 bits or more leaves nothing
+        let shr = |n: i64, arg: i64| u32::try_from(combo(arg)).ok().and_then(|shift| n.checked_shr(shift)).unwrap_or(0);
+        let literal = |arg: i64| arg;
+
+        match instr {
+            Adv(arg) => a = shr(a, arg),
+            Bxl(arg) => b = b ^ literal(arg),
+            Bst(arg) => b = combo(arg) % 8,
+            Jnz(arg) => match a {
+                0 => (),
+                _ => ip = literal(arg) as usize,
+            },
+            Bxc(_arg) => b = b ^ c,
+            Out(arg) => output.push(combo(arg) % 8),
+            Bdv(arg) => b = shr(a, arg),
+            Cdv(arg) => c = shr(a, arg),
+        };
+        Self { a, b, c, ip, program: self.program.to_vec(), output }
+    }
+
+    /// The program running from this state, one instruction at a time.
+    pub fn simulation(&self) -> Simulation<Self, impl Fn(&Self) -> Option<Self>> {
+        Simulation::new(self.clone(), |machine: &Self| {
+            (machine.ip < machine.program.len()).then(|| machine.execute_one())
+        })
+    }
+
+    /// The registers, program and output, highlighting the next instruction
+    /// and the registers that `prev` had different.
+    fn frame(&self, prev: Option<&Self>) -> Frame {
+        let mut program = String::from("Program: ");
+        let mut columns = vec![];
+        for (i, value) in self.program.iter().enumerate() {
+            if i > 0 {
+                program.push(',');
+            }
+            columns.push((program.len(), value.to_string().len()));
+            program.push_str(&value.to_string());
+        }
+        let output: Vec<String> = self.output.iter().map(|n| n.to_string()).collect();
+        let text = format!(
+            "Register A: {}\nRegister B: {}\nRegister C: {}\n\n{}\nOutput:  {}",
+            self.a,
+            self.b,
+            self.c,
+            program,
+            output.join(","),
+        );
+        let mut frame = Frame::text(&text);
+        if let Some(prev) = prev {
+            for (y, changed) in [prev.a != self.a, prev.b != self.b, prev.c != self.c].into_iter().enumerate() {
+                for x in (0..frame.cells().width()).filter(|_| changed) {
+                    frame.highlight(Pos { y: y as i64, x: x as i64 }, Color::Red);
+                }
+            }
+        }
+        for &(start, len) in columns.iter().skip(self.ip).take(2) {
+            for x in start..start + len {
+                frame.highlight(Pos { y: 4, x: x as i64 }, Color::Yellow);
+            }
+        }
+        let caption = match self.program.get(self.ip..self.ip + 2) {
+            Some(&[opcode, operand]) => {
+                let (op, arg) = Instruction::parse(opcode, operand).mnemonic();
+                format!("ip {}: {} {}", self.ip, op, arg)
+            },
+            _ => "Halted".to_string(),
+        };
+        frame.with_caption(caption)
+    }
+
+    /// The machine once the program halts.
+    pub fn run_program(&self) -> Result<Self, SolveError> {
+        match self.simulation().brent().limit(MAX_STEPS).run() {
+            Outcome::Halted { last, .. } => Ok(last),
+            Outcome::Cycle(Cycle { start, period }) => Err(SolveError::new(format!(
+                "the program never halts: after {} instructions it goes round a loop of {}",
+                start, period,
+            ))),
+            Outcome::GaveUp { .. } => {
+                Err(SolveError::new(format!("the program did not halt within {} instructions", MAX_STEPS)))
+            },
+        }
+    }
+
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    /// The same machine with register A set to `a`.
+    pub fn update_a(&self, a: i64) -> Self {
+        Self { a, program: self.program.to_vec(), output: Vec::new(), ..*self }
+    }
+}
+
+fn byte_groups(n: i64) -> String {
+    let mut v = Vec::new();
+    let mut x = n;
+    while x > 0 {
+        v.push(format!("{:03b} ", x % 8));
+        x /= 8;
+    }
+    v.reverse();
+    v.join(" ")
+}
+
+impl Solution for Machine {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let re = Regex::new(r"Register A: (\d+)
+Register B: (\d+)
+Register C: (\d+)
+
+Program: ([\d,]+)").unwrap();
+
+        let caps = re.captures(input).ok_or_else(|| {
+            src.error(input, "expected \"Register A/B/C: ...\" followed by \"Program: ...\"")
+        })?;
+        let (_, [a_s, b_s, c_s, program_s]) = caps.extract();
+        let a: i64 = src.num(a_s)?;
+        let b: i64 = src.num(b_s)?;
+        let c: i64 = src.num(c_s)?;
+        let values: Vec<&str> = program_s.split(",").collect();
+        let program: Vec<i64> = values
+            .iter()
+            .map(|s| match src.num::<i64>(s)? {
+                n @ 0..=7 => Ok(n),
+                _ => Err(src.error(s, "expected a 3-bit number (0-7)")),
+            })
+            .collect::<Result<_, _>>()?;
+        if !program.len().is_multiple_of(2) {
+            return Err(src.error(program_s, "expected an operand after every opcode"));
+        }
+        for (i, pair) in program.chunks(2).enumerate() {
+            match (pair[0], pair[1]) {
+                (0 | 2 | 5 | 6 | 7, 7) => return Err(src.error(values[2 * i + 1], "combo operand 7 is reserved")),
+                (3, target) if target % 2 != 0 => {
+                    return Err(src.error(values[2 * i + 1], "jumps must land on an opcode, not an operand"));
+                },
+                _ => (),
+            }
+        }
+        Ok(Self { a, b, c, ip: 0, program, output: Vec::new() })
+    }
+
+    /// 2: part 2 tries every value of the lowest 10 bits of A, where it used
+    /// to miss the highest.
+    fn version() -> &'static str {
+        "2"
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        let part1 = self.run_program()?;
+        debug!(outputs = part1.output.len(), "program halted");
+        Ok(part1.output
+            .iter().map(|n| n.to_string()).collect::<Vec<String>>().join(",").into())
+    }
+
+    /// The machine before each instruction part 1 runs, and once it halts.
+    fn visualize(&self, part: u8) -> Option<Frames<'_>> {
+        if part != 1 {
+            return None;
+        }
+        let mut prev = None;
+        let states = self.simulation().brent().states().take(MAX_STEPS + 1).map_while(Result::ok);
+        Some(Box::new(states.map(move |machine| {
+            let frame = machine.frame(prev.as_ref());
+            prev = Some(machine);
+            frame
+        })))
+    }
+
+    /// Each instruction run by part 1, with the registers it changes and what
+    /// it outputs.
+    fn trace(&self, part: u8) -> Option<Events<'_>> {
+        if part != 1 {
+            return None;
+        }
+        let mut states = self.simulation().brent().states().take(MAX_STEPS + 1);
+        let mut prev = states.next()?.ok()?;
+        Some(Box::new(states.map(move |state| {
+            let next = match state {
+                Ok(next) => next,
+                Err(Cycle { start, period }) => {
+                    return Event::new("loop").with("start", start).with("period", period);
+                },
+            };
+            let (op, arg) = Instruction::parse(prev.program[prev.ip], prev.program[prev.ip + 1]).mnemonic();
+            let mut event = Event::new("exec").with("ip", prev.ip).with("op", op).with("arg", arg);
+            for (name, old, new) in [("a", prev.a, next.a), ("b", prev.b, next.b), ("c", prev.c, next.c)] {
+                if old != new {
+                    event = event.with(name, format!("{}>{}", old, new));
+                }
+            }
+            if let Some(out) = next.output.get(prev.output.len()) {
+                event = event.with("out", out);
+            }
+            prev = next;
+            event
+        })))
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        if self.program.len() > MAX_PART2_LEN {
+            return Err(SolveError::new(format!("programs longer than {} values are not supported", MAX_PART2_LEN)));
+        }
+        // See analysis below for how we calculate part 2
+        let mut candidates = parallel::filter((0..=0b1_111_111_111).collect(), |a| {
+            self.update_a(*a).run_program().is_ok_and(|m| m.output.first() == Some(&self.program[0]))
+        });
+        debug!(candidates = candidates.len(), "matched first output");
+        for group in 1..self.program.len() {
+            // Output #group depends on the 10 bits starting at 3 * group, of
+            // which we have already fixed the lowest 7 for each candidate.
+            let bit_shift = 3 * group + 7;
+            let extended = candidates
+                .iter()
+                .flat_map(|a| (0b000..=0b111).map(move |bits| a | (bits << bit_shift)))
+                .collect();
+            candidates = parallel::filter(extended, |a| {
+                self.update_a(*a).run_program().is_ok_and(|m| {
+                    m.output.len() > group && m.output[..=group] == self.program[..=group]
+                })
+            });
+            debug!(group, candidates = candidates.len(), "matched output");
+            trace!(group, candidates = ?candidates.iter().map(|a| byte_groups(*a)).collect::<Vec<_>>());
+        }
+        Ok(candidates
+            .into_iter()
+            .filter(|a| self.update_a(*a).run_program().is_ok_and(|m| m.output == self.program))
+            .min()
+            .ok_or_else(|| SolveError::new("no value of register A makes the program output itself"))?
+            .into())
+    }
+}
+
+// Register A: ???
+// Register B: 0
+// Register C: 0
+// Program:
+//      Bst 4
+//      Bxl 5
+//      Cdv 5
+//      Bxl 6
+//      Adv 3
+//      Bxc 3
+//      Out 5
+//      Jnz 0
+// Transcoded:
+//  loop {
+//      b = a % 8
+//      b = b ^ 0b101
+//      c = a / (1 << b)
+//      b = b ^ 0b110
+//      a /= 8
+//      b = b ^ c
+//      out(b % 8)
+//      if a == 0 { break }
+//  }
+//  loop {
+//      b = (a % 8) ^ 0b101
+//      c = a >> b
+//      b = b ^ 0b110
+//      out((b ^ c) % 8)
+//      a >>= 3
+//      if a == 0 { break }
+//  }
+// Analysis:
+//  - a is divided by 8 for each loop iteration
+//  - One int is output for each loop iteration
+//  - Program is 16 ints long, hence a must be >= 2^(15*3) and < 2^(16*3)
+//  - Each iteration:
+//      - b = lowest 3 bits in a, with first and third bits flipped
+//          - a == 0b*000 -> b = 0b101 = 5
+//          - a == 0b*001 -> b = 0b100 = 4
+//          - a == 0b*010 -> b = 0b111 = 7
+//          - a == 0b*011 -> b = 0b110 = 6
+//          - a == 0b*100 -> b = 0b001 = 1
+//          - a == 0b*101 -> b = 0b000 = 0
+//          - a == 0b*110 -> b = 0b011 = 3
+//          - a == 0b*111 -> b = 0b010 = 2
+//      - c = a / 2^b - a divided by some power of 2 ==> right shift
+//          - c = a >> b, lowest bits of c is taken from 0-7 higher bits in a 
+//      - b ^= 0b110
+//          - a == 0b*000 --> b = 0b011 = 3
+//          - a == 0b*001 --> b = 0b010 = 2
+//          - a == 0b*010 --> b = 0b001 = 1
+//          - a == 0b*011 --> b = 0b000 = 0
+//          - a == 0b*100 --> b = 0b111 = 7
+//          - a == 0b*101 --> b = 0b110 = 6
+//          - a == 0b*110 --> b = 0b101 = 5
+//          - a == 0b*111 --> b = 0b100 = 4
+//      - out((b ^ c) % 8) - output 3 lowest bits of c XORed with b
+//          depends on result of complicated division above
+//  - Each value that is output depends "mostly" on the lowest 3 bits of a,
+//    but _really_ on the lowest 10 bits of a.
+//  - We know that there are less that 16*3 bits in a, so the last ~4 values
+//    output are bound by min(10, (16 - group) * 3)
+//  - The _last_ value output is determined wholly by the top 3 bits of a.
+//  - The plan:
+//      - Count through the lowest 10 bits of a, keep all values that yield the
+//        correct first output value. There should be ~127 in total.
+//      - For each subsequent output value, vary the 3 next higher bits until
+//        that output value matches. Assert that previous outputs are correct.
+//      - Keep going until all output values are correct.
+//      - Output minimum candidate value for a
+
+/// Parse the registers and the program.
+pub fn parse(input: &str, params: &Params) -> Result<Machine, ParseError> {
+    Machine::from_input(input, params)
+}
+
+/// The program's output, joined with commas.
+pub fn solve_part1(machine: &Machine) -> Result<Answer, SolveError> {
+    machine.part1()
+}
+
+/// The lowest value of register A that makes the program output a copy of itself.
+pub fn solve_part2(machine: &Machine) -> Result<Answer, SolveError> {
+    machine.part2()
+}
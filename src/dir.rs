@@ -0,0 +1,74 @@
+use crate::Pos;
+
+/// One of the 4 orthogonal directions on a grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// All directions, in clockwise order starting from `Up`.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Parse one of the arrow characters `^`, `>`, `v` or `<`.
+    pub fn parse(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Self::Up),
+            '>' => Some(Self::Right),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+
+    /// The offset of one step in this direction.
+    pub fn pos(self) -> Pos {
+        match self {
+            Self::Up => Pos { y: -1, x: 0 },
+            Self::Right => Pos { y: 0, x: 1 },
+            Self::Down => Pos { y: 1, x: 0 },
+            Self::Left => Pos { y: 0, x: -1 },
+        }
+    }
+
+    /// Turn 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// Turn 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+}
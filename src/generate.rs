@@ -0,0 +1,437 @@
+//! Seeded random puzzle inputs, for stress tests and scaling benchmarks.
+//!
+//! What `size` controls varies by day:
+//!
+//! | Day | Size                        | Day | Size                          |
+//! |-----|-----------------------------|-----|-------------------------------|
+//! | 01  | number of lines             | 10  | map width and height          |
+//! | 02  | number of reports           | 11  | number of stones              |
+//! | 03  | number of instructions      | 12  | garden width and height       |
+//! | 04  | grid width and height       | 13  | number of claw machines       |
+//! | 05  | number of updates           | 14  | number of robots              |
+//! | 06  | map width and height        | 15  | warehouse width and height    |
+//! | 07  | number of equations         | 16  | maze width and height         |
+//! | 08  | map width and height        | 17  | number of instruction blocks  |
+//! | 09  | number of files             |     |                               |
+//!
+//! Sizes are clamped to what each day supports, e.g. day 17 programs stay
+//! short enough for part 2. Inputs assume the default puzzle parameters, e.g.
+//! day14's 101x103 room.
+
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+use crate::day17::MAX_PART2_LEN;
+use crate::{Dir, Grid, Pos};
+
+type GenerateFn = fn(&mut StdRng, usize) -> String;
+
+/// A day's input generator, the size of its real puzzle input and the sizes
+/// it supports.
+pub struct Generator {
+    pub num: u8,
+    pub default_size: usize,
+    pub sizes: RangeInclusive<usize>,
+    generate: GenerateFn,
+}
+
+impl Generator {
+    const fn new(num: u8, default_size: usize, sizes: RangeInclusive<usize>, generate: GenerateFn) -> Self {
+        Self { num, default_size, sizes, generate }
+    }
+
+    /// Generate an input, with `size` clamped to the supported sizes. The same
+    /// seed and size always give the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let size = size.clamp(*self.sizes.start(), *self.sizes.end());
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+/// Sizes of `min` or more.
+const fn at_least(min: usize) -> RangeInclusive<usize> {
+    min..=usize::MAX
+}
+
+/// Day 17 programs start with 2 values and loop with 6, and each block takes
+/// at least 2 more.
+const DAY17_MAX_BLOCKS: usize = (MAX_PART2_LEN - 8) / 2;
+
+pub const GENERATORS: &[Generator] = &[
+    Generator::new(1, 1000, at_least(1), day01),
+    Generator::new(2, 1000, at_least(1), day02),
+    Generator::new(3, 700, at_least(1), day03),
+    Generator::new(4, 140, at_least(1), day04),
+    Generator::new(5, 200, at_least(1), day05),
+    Generator::new(6, 130, at_least(3), day06),
+    Generator::new(7, 850, at_least(1), day07),
+    Generator::new(8, 50, at_least(1), day08),
+    Generator::new(9, 10000, at_least(1), day09),
+    Generator::new(10, 50, at_least(1), day10),
+    Generator::new(11, 8, at_least(1), day11),
+    Generator::new(12, 140, at_least(1), day12),
+    Generator::new(13, 320, at_least(1), day13),
+    Generator::new(14, 500, at_least(9), day14),
+    Generator::new(15, 50, at_least(4), day15),
+    Generator::new(16, 141, at_least(5), day16),
+    Generator::new(17, 2, 0..=DAY17_MAX_BLOCKS, day17),
+];
+
+pub fn generator(num: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.num == num)
+}
+
+fn render_grid(grid: &Grid<u8>) -> String {
+    grid.render(|_, byte| *byte as char)
+}
+
+/// A random position strictly inside the border of `grid`.
+fn inner_pos(rng: &mut StdRng, grid: &Grid<u8>) -> Pos {
+    Pos { y: rng.random_range(1..grid.size().y - 1), x: rng.random_range(1..grid.size().x - 1) }
+}
+
+/// A number of 1 to `max_digits` digits, with each length equally likely.
+fn random_digits(rng: &mut StdRng, max_digits: u32) -> u64 {
+    let digits = rng.random_range(1..=max_digits);
+    rng.random_range(10u64.pow(digits - 1)..10u64.pow(digits))
+}
+
+fn day01(rng: &mut StdRng, size: usize) -> String {
+    let left: Vec<u32> = (0..size).map(|_| rng.random_range(10000..100000)).collect();
+    let mut ret = String::new();
+    for l in left.iter() {
+        // Reuse left numbers on the right now and then, so there is something to count
+        let r = match rng.random_bool(0.3) {
+            true => *left.choose(rng).unwrap(),
+            false => rng.random_range(10000..100000),
+        };
+        ret += &format!("{}   {}\n", l, r);
+    }
+    ret
+}
+
+fn day02(rng: &mut StdRng, size: usize) -> String {
+    let mut ret = String::new();
+    for _ in 0..size {
+        let len = rng.random_range(5..=8);
+        let sign = if rng.random_bool(0.5) { 1 } else { -1 };
+        let mut levels = vec![rng.random_range(30..70)];
+        for _ in 1..len {
+            levels.push(levels[levels.len() - 1] + sign * rng.random_range(1..=3));
+        }
+        if rng.random_bool(0.5) {
+            // A bad level: a big jump, a repeat or a change of direction
+            let i = rng.random_range(0..len);
+            levels[i] += [-7, -1, 0, 1, 7].choose(rng).unwrap();
+        }
+        ret += &levels.iter().map(|level| level.to_string()).collect::<Vec<_>>().join(" ");
+        ret.push('\n');
+    }
+    ret
+}
+
+fn day03(rng: &mut StdRng, size: usize) -> String {
+    const JUNK: &[&str] = &[
+        "", "", "!", "@", "^", "&", "[", "]", ")", "+", " ", "'", "mul(", "mul[3,4]", "mul ( 2 , 4 )",
+        "what()", "select()", "from()", "how()", "mul(4*", "do_not_mul(", "?(12,34)",
+    ];
+    let mut ret = String::new();
+    for i in 0..size {
+        ret += JUNK.choose(rng).unwrap();
+        match rng.random_range(0..10) {
+            0 => ret += "do()",
+            1 => ret += "don't()",
+            _ => ret += &format!("mul({},{})", rng.random_range(1..1000), rng.random_range(1..1000)),
+        }
+        ret += JUNK.choose(rng).unwrap();
+        if i % 100 == 99 {
+            ret.push('\n');
+        }
+    }
+    ret.push('\n');
+    ret
+}
+
+fn day04(rng: &mut StdRng, size: usize) -> String {
+    render_grid(&Grid::from_fn(Pos { y: size as i64, x: size as i64 }, |_| *b"XMAS".choose(rng).unwrap()))
+}
+
+fn day05(rng: &mut StdRng, size: usize) -> String {
+    // Every pair of pages has a rule, following one hidden order
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(rng);
+    order.truncate(49);
+    let mut rules: Vec<(u32, u32)> = vec![];
+    for (i, before) in order.iter().enumerate() {
+        for after in order[i + 1..].iter() {
+            rules.push((*before, *after));
+        }
+    }
+    rules.shuffle(rng);
+
+    let mut ret = String::new();
+    for (before, after) in rules.iter() {
+        ret += &format!("{}|{}\n", before, after);
+    }
+    ret.push('\n');
+    for _ in 0..size {
+        let len = 2 * rng.random_range(2..=11) + 1;
+        let mut update: Vec<u32> = order.choose_multiple(rng, len).copied().collect();
+        if rng.random_bool(0.5) {
+            update.sort_by_key(|page| order.iter().position(|p| p == page));
+        }
+        ret += &update.iter().map(|page| page.to_string()).collect::<Vec<_>>().join(",");
+        ret.push('\n');
+    }
+    ret
+}
+
+/// The number of steps a guard starting at `start` facing up takes to walk
+/// off `grid`, or `None` if it gets stuck in a loop.
+fn patrol_len(grid: &Grid<u8>, start: Pos) -> Option<usize> {
+    let mut seen = HashSet::new();
+    let (mut pos, mut dir) = (start, Dir::Up);
+    while seen.insert((pos, dir)) {
+        match grid.get(pos + dir.pos()) {
+            None => return Some(seen.len()),
+            Some(b'#') => dir = dir.turn_right(),
+            Some(_) => pos += dir.pos(),
+        }
+    }
+    None
+}
+
+fn day06(rng: &mut StdRng, size: usize) -> String {
+    // Like the real puzzle, the guard must not be stuck in a loop to begin with,
+    // and should patrol for a while before leaving
+    loop {
+        let mut grid = Grid::from_fn(Pos { y: size as i64, x: size as i64 }, |_| match rng.random_bool(0.1) {
+            true => b'#',
+            false => b'.',
+        });
+        let start = inner_pos(rng, &grid);
+        grid[start] = b'^';
+        if patrol_len(&grid, start).is_some_and(|len| len >= size) {
+            return render_grid(&grid);
+        }
+    }
+}
+
+fn day07(rng: &mut StdRng, size: usize) -> String {
+    let mut ret = String::new();
+    for _ in 0..size {
+        // Results can't have more digits than all the inputs together, so keep
+        // those below 18 to fit in a u64
+        let mut inputs: Vec<u64> = vec![];
+        let mut digits = 0;
+        for _ in 0..rng.random_range(2..=10) {
+            let input = random_digits(rng, 3);
+            digits += input.to_string().len();
+            if digits > 17 {
+                break;
+            }
+            inputs.push(input);
+        }
+        let result = match rng.random_bool(0.5) {
+            true => inputs[1..].iter().fold(inputs[0], |acc, n| match rng.random_range(0..3) {
+                0 => acc + n,
+                1 => acc * n,
+                _ => format!("{}{}", acc, n).parse().unwrap(),
+            }),
+            false => rng.random_range(1..1_000_000_000),
+        };
+        let inputs: Vec<String> = inputs.iter().map(|n| n.to_string()).collect();
+        ret += &format!("{}: {}\n", result, inputs.join(" "));
+    }
+    ret
+}
+
+fn day08(rng: &mut StdRng, size: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut grid = Grid::from_fn(Pos { y: size as i64, x: size as i64 }, |_| b'.');
+    let count = (size / 5).clamp(1, FREQUENCIES.len());
+    for freq in FREQUENCIES.choose_multiple(rng, count) {
+        for _ in 0..rng.random_range(2..=4) {
+            let pos = Pos { y: rng.random_range(0..size as i64), x: rng.random_range(0..size as i64) };
+            grid[pos] = *freq;
+        }
+    }
+    render_grid(&grid)
+}
+
+fn day09(rng: &mut StdRng, size: usize) -> String {
+    let mut ret = String::new();
+    for i in 0..size {
+        ret += &rng.random_range(1..=9).to_string();
+        if i + 1 < size {
+            ret += &rng.random_range(0..=9).to_string();
+        }
+    }
+    ret.push('\n');
+    ret
+}
+
+fn day10(rng: &mut StdRng, size: usize) -> String {
+    let mut grid = Grid::from_fn(Pos { y: size as i64, x: size as i64 }, |_| rng.random_range(b'0'..=b'9'));
+    // Lay down random uphill trails, as random heights rarely make any
+    for _ in 0..size * size / 10 {
+        let mut trail = vec![Pos { y: rng.random_range(0..size as i64), x: rng.random_range(0..size as i64) }];
+        while trail.len() < 10 {
+            let pos = trail[trail.len() - 1];
+            let nbors: Vec<Pos> = pos.adjacents()
+                .filter(|nbor| grid.contains(*nbor) && !trail.contains(nbor))
+                .collect();
+            match nbors.choose(rng) {
+                Some(nbor) => trail.push(*nbor),
+                None => break,
+            }
+        }
+        if trail.len() == 10 {
+            for (pos, height) in trail.into_iter().zip(b'0'..=b'9') {
+                grid[pos] = height;
+            }
+        }
+    }
+    render_grid(&grid)
+}
+
+fn day11(rng: &mut StdRng, size: usize) -> String {
+    let stones: Vec<String> = (0..size)
+        .map(|_| random_digits(rng, 6).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+fn day12(rng: &mut StdRng, size: usize) -> String {
+    // Regions are the cells nearest to random seeds, with plant types reused
+    let seeds: Vec<(Pos, u8)> = (0..(size * size / 20).max(1))
+        .map(|_| {
+            let pos = Pos { y: rng.random_range(0..size as i64), x: rng.random_range(0..size as i64) };
+            (pos, rng.random_range(b'A'..=b'Z'))
+        })
+        .collect();
+    render_grid(&Grid::from_fn(Pos { y: size as i64, x: size as i64 }, |pos| {
+        seeds.iter().min_by_key(|(seed, _)| (*seed - pos).y.abs() + (*seed - pos).x.abs()).unwrap().1
+    }))
+}
+
+fn day13(rng: &mut StdRng, size: usize) -> String {
+    let mut machines = vec![];
+    for _ in 0..size {
+        let [ax, ay, bx, by]: [u64; 4] = [(); 4].map(|_| rng.random_range(10..100));
+        let (px, py) = match rng.random_bool(0.5) {
+            true => {
+                let (a, b) = (rng.random_range(1..=100), rng.random_range(1..=100));
+                (a * ax + b * bx, a * ay + b * by)
+            },
+            false => (rng.random_range(1000..20000), rng.random_range(1000..20000)),
+        };
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n", ax, ay, bx, by, px, py,
+        ));
+    }
+    machines.join("\n")
+}
+
+fn day14(rng: &mut StdRng, size: usize) -> String {
+    let bounds = Pos { y: 103, x: 101 };
+    let mut robots: Vec<(Pos, Pos)> = vec![];
+    let random_vel = |rng: &mut StdRng| loop {
+        let vel = Pos { y: rng.random_range(-99..=99), x: rng.random_range(-99..=99) };
+        if vel != Pos::ORIGIN {
+            return vel;
+        }
+    };
+    // Part 2 runs until some robot is surrounded, so plant a 3x3 block that
+    // forms after a random number of seconds, working back to where it starts
+    let seconds = rng.random_range(0..bounds.y * bounds.x);
+    let center = Pos { y: rng.random_range(1..bounds.y - 1), x: rng.random_range(1..bounds.x - 1) };
+    for pos in center.surrounding().chain([center]) {
+        let vel = random_vel(rng);
+        robots.push(((pos - vel * seconds).wrap(bounds), vel));
+    }
+    for _ in 9..size {
+        let pos = Pos { y: rng.random_range(0..bounds.y), x: rng.random_range(0..bounds.x) };
+        robots.push((pos, random_vel(rng)));
+    }
+    robots.shuffle(rng);
+    robots.iter().map(|(pos, vel)| format!("p={},{} v={},{}\n", pos.x, pos.y, vel.x, vel.y)).collect()
+}
+
+fn day15(rng: &mut StdRng, size: usize) -> String {
+    let size = size as i64;
+    let mut grid = Grid::from_fn(Pos { y: size, x: size }, |pos| {
+        match pos.y == 0 || pos.x == 0 || pos.y == size - 1 || pos.x == size - 1 {
+            true => b'#',
+            false => *b"....#OOO".choose(rng).unwrap(),
+        }
+    });
+    let robot = inner_pos(rng, &grid);
+    grid[robot] = b'@';
+    let moves: Vec<u8> = (0..8 * size * size).map(|_| *b"^>v<".choose(rng).unwrap()).collect();
+    let mut ret = render_grid(&grid);
+    for line in moves.chunks(1000) {
+        ret.push('\n');
+        ret += std::str::from_utf8(line).unwrap();
+    }
+    ret.push('\n');
+    ret
+}
+
+fn day16(rng: &mut StdRng, size: usize) -> String {
+    // Carve a maze through the odd positions with a randomized depth-first
+    // search, then knock out some extra walls so there are several best paths
+    let size = (size | 1) as i64;
+    let mut grid = Grid::from_fn(Pos { y: size, x: size }, |_| b'#');
+    let mut stack = vec![Pos { y: size - 2, x: 1 }];
+    grid[stack[0]] = b'.';
+    while let Some(&pos) = stack.last() {
+        let nexts: Vec<Pos> = [Pos { y: -2, x: 0 }, Pos { y: 2, x: 0 }, Pos { y: 0, x: -2 }, Pos { y: 0, x: 2 }]
+            .into_iter()
+            .map(|step| pos + step)
+            .filter(|next| next.y > 0 && next.x > 0 && next.y < size - 1 && next.x < size - 1)
+            .filter(|next| grid[*next] == b'#')
+            .collect();
+        match nexts.choose(rng) {
+            Some(&next) => {
+                grid[Pos { y: (pos.y + next.y) / 2, x: (pos.x + next.x) / 2 }] = b'.';
+                grid[next] = b'.';
+                stack.push(next);
+            },
+            None => { stack.pop(); },
+        }
+    }
+    for _ in 0..size * size / 50 {
+        let pos = inner_pos(rng, &grid);
+        grid[pos] = b'.';
+    }
+    grid[Pos { y: size - 2, x: 1 }] = b'S';
+    grid[Pos { y: 1, x: size - 2 }] = b'E';
+    render_grid(&grid)
+}
+
+/// Programs have the shape of the real ones: take the low 3 bits of A into B,
+/// scramble B, output it, shift A by 3 bits and loop until A is zero. Each
+/// scrambling block leaves B below 8, so shifts by B stay in range. Part 2
+/// only has an answer if the program happens to be able to output itself.
+fn day17(rng: &mut StdRng, size: usize) -> String {
+    let mut program = vec![2, 4];
+    for block in 0..size {
+        // Only pick blocks that leave room for the rest at 2 values each
+        let room = MAX_PART2_LEN - 6 - program.len() - 2 * (size - block - 1);
+        let bits = rng.random_range(0..8);
+        let blocks: Vec<Vec<u8>> = [vec![1, bits], vec![7, 5, 4, 0, 2, 5], vec![1, bits, 7, 5, 4, 0, 2, 5]]
+            .into_iter()
+            .filter(|block| block.len() <= room)
+            .collect();
+        program.extend(blocks.choose(rng).unwrap());
+    }
+    program.extend([5, 5, 0, 3, 3, 0]);
+    let program: Vec<String> = program.iter().map(|n: &u8| n.to_string()).collect();
+    let a: u64 = rng.random_range(1..1 << 30);
+    format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", a, program.join(","))
+}
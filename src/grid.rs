@@ -0,0 +1,212 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{ParseError, Pos};
+
+/// A dense, rectangular 2D grid of cells, stored row by row in a flat `Vec`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from lines of text, mapping each byte (and its position)
+    /// to a cell. All lines must be of equal width.
+    pub fn parse<I, S, F>(lines: I, mut f: F) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(Pos, u8) -> T,
+    {
+        Self::try_parse(lines, |pos, byte| Some(f(pos, byte)))
+    }
+
+    /// Like `parse`, but the mapping may reject a byte by returning `None`.
+    pub fn try_parse<I, S, F>(lines: I, mut f: F) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(Pos, u8) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref();
+            match width {
+                None => width = Some(line.len()),
+                Some(n) if n != line.len() => return Err(ParseError::new(
+                    y + 1, 1, line, format!("expected {} characters in line, found {}", n, line.len()),
+                )),
+                Some(_) => (),
+            }
+            for (x, byte) in line.as_bytes().iter().enumerate() {
+                match f(Pos { y: y as i64, x: x as i64 }, *byte) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        let c = line[x..].chars().next().unwrap();
+                        let column = line[..x].chars().count() + 1;
+                        return Err(ParseError::new(y + 1, column, &c.to_string(), "unexpected character"));
+                    },
+                }
+            }
+            height += 1;
+        }
+        Ok(Self { cells, height, width: width.unwrap_or(0) })
+    }
+
+    /// Build a grid of the given size, calling `f` for each position.
+    pub fn from_fn<F>(size: Pos, mut f: F) -> Self
+    where
+        F: FnMut(Pos) -> T,
+    {
+        assert!(size.y >= 0 && size.x >= 0);
+        let (height, width) = (size.y as usize, size.x as usize);
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos { y: y as i64, x: x as i64 }))
+            .map(&mut f)
+            .collect();
+        Self { cells, height, width }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The size of the grid, i.e. the position just past the bottom right corner.
+    pub fn size(&self) -> Pos {
+        Pos { y: self.height as i64, x: self.width as i64 }
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.y >= 0 && pos.x >= 0 && (pos.y as usize) < self.height && (pos.x as usize) < self.width
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        match self.contains(pos) {
+            true => Some(pos.y as usize * self.width + pos.x as usize),
+            false => None,
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// All positions in the grid, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Pos { y: y as i64, x: x as i64 }))
+    }
+
+    /// All positions paired with their cells, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell (in reading order) matching `pred`.
+    pub fn find<P>(&self, mut pred: P) -> Option<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid { cells: self.cells.iter().map(f).collect(), height: self.height, width: self.width }
+    }
+
+    /// Rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Columns, from left to right, each one iterated from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| (0..self.height).map(move |y| &self.cells[y * self.width + x]))
+    }
+
+    /// Diagonals running from top left to bottom right, starting with the one
+    /// in the bottom left corner and ending with the one in the top right.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (h, w) = (self.height as i64, self.width as i64);
+        (1 - h..w).map(move |d| {
+            (0..h)
+                .map(move |y| Pos { y, x: y + d })
+                .filter_map(|pos| self.get(pos))
+        })
+    }
+
+    /// Diagonals running from top right to bottom left, starting with the one
+    /// in the top left corner and ending with the one in the bottom right.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (h, w) = (self.height as i64, self.width as i64);
+        (0..h + w - 1).map(move |d| {
+            (0..h)
+                .map(move |y| Pos { y, x: d - y })
+                .filter_map(|pos| self.get(pos))
+        })
+    }
+
+    /// Render the grid one character per cell, one line per row.
+    pub fn render<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(Pos, &T) -> char,
+    {
+        let mut s = String::with_capacity(self.height * (self.width + 1));
+        for (pos, cell) in self.iter() {
+            s.push(f(pos, cell));
+            if pos.x as usize + 1 == self.width {
+                s.push('\n');
+            }
+        }
+        s
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside grid of size {:?}", pos, self.size()),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let size = self.size();
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside grid of size {:?}", pos, size),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
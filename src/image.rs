@@ -0,0 +1,139 @@
+//! Images of frames, for sharing visualizations outside a terminal.
+//!
+//! PPM is always available. PNG and animated GIF need the `images` feature.
+
+use std::error::Error;
+use std::io::{self, Write};
+use std::iter;
+
+use crate::{Cell, Color, Frame};
+
+pub type Rgb = [u8; 3];
+
+/// The colours to draw a day's frames in.
+#[derive(Clone, Copy, Debug)]
+pub struct Palette {
+    /// Cells shown as '.' or ' ' without a colour.
+    pub background: Rgb,
+    /// Any other cell without a colour.
+    pub foreground: Rgb,
+    /// Replacements for some of the colours; the rest keep their usual look.
+    pub colors: &'static [(Color, Rgb)],
+}
+
+impl Palette {
+    pub const DEFAULT: Self = Self { background: [16, 16, 24], foreground: [200, 200, 200], colors: &[] };
+
+    pub fn rgb(&self, cell: Cell) -> Rgb {
+        match cell.color {
+            Some(color) => self.colors.iter()
+                .find(|(c, _)| *c == color)
+                .map_or_else(|| default_rgb(color), |(_, rgb)| *rgb),
+            None if cell.ch == '.' || cell.ch == ' ' => self.background,
+            None => self.foreground,
+        }
+    }
+
+    /// Draw each cell of `frame` as a `scale` by `scale` square.
+    pub fn paint(&self, frame: &Frame, scale: usize) -> Image {
+        let cells = frame.cells();
+        let (width, height) = (cells.width() * scale, cells.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in cells.rows() {
+            let line: Vec<Rgb> = row.iter().flat_map(|cell| iter::repeat_n(self.rgb(*cell), scale)).collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Image { width, height, pixels }
+    }
+}
+
+fn default_rgb(color: Color) -> Rgb {
+    match color {
+        Color::Red => [230, 60, 60],
+        Color::Green => [60, 200, 80],
+        Color::Yellow => [240, 210, 60],
+        Color::Blue => [70, 120, 240],
+        Color::Magenta => [210, 80, 210],
+        Color::Cyan => [70, 210, 220],
+        Color::White => [250, 250, 250],
+        Color::Gray => [110, 110, 120],
+    }
+}
+
+/// An RGB image, stored row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Write as a binary PPM. Several images written one after another make
+    /// a valid multi-image PPM stream.
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(self.pixels.as_flattened())
+    }
+
+    #[cfg(feature = "images")]
+    pub fn write_png(&self, out: impl Write) -> Result<(), Box<dyn Error>> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(self.pixels.as_flattened())?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "images"))]
+    pub fn write_png(&self, _out: impl Write) -> Result<(), Box<dyn Error>> {
+        Err(NEEDS_FEATURE.into())
+    }
+}
+
+#[cfg(not(feature = "images"))]
+const NEEDS_FEATURE: &str = "PNG and GIF output need the \"images\" feature (cargo build --features images)";
+
+/// Write `images` as a looping animated GIF, showing each for `delay`
+/// hundredths of a second. All images must be the same size.
+#[cfg(feature = "images")]
+pub fn write_gif(images: impl Iterator<Item = Image>, delay: u16, out: impl Write) -> Result<usize, Box<dyn Error>> {
+    let mut images = images.peekable();
+    let Some(first) = images.peek() else {
+        return Err("no frames to write".into());
+    };
+    let (width, height) = (u16::try_from(first.width)?, u16::try_from(first.height)?);
+    let mut encoder = gif::Encoder::new(out, width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    let mut written = 0;
+    for image in images {
+        if (image.width, image.height) != (width as usize, height as usize) {
+            return Err(format!("frame {} is not the same size as the first", written).into());
+        }
+        // Frames only use a handful of colours, so index them exactly
+        let mut colors: Vec<Rgb> = vec![];
+        let mut indexes = Vec::with_capacity(image.pixels.len());
+        for pixel in image.pixels.iter() {
+            let i = match colors.iter().position(|c| c == pixel) {
+                Some(i) => i,
+                None => {
+                    colors.push(*pixel);
+                    colors.len() - 1
+                },
+            };
+            indexes.push(u8::try_from(i).map_err(|_| "a frame has more than 256 colours")?);
+        }
+        let mut frame = gif::Frame::from_palette_pixels(width, height, indexes, colors.as_flattened(), None);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+#[cfg(not(feature = "images"))]
+pub fn write_gif(_images: impl Iterator<Item = Image>, _delay: u16, _out: impl Write) -> Result<usize, Box<dyn Error>> {
+    Err(NEEDS_FEATURE.into())
+}
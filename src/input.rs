@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+/// Tidy up an input as saved by an editor or pasted into a terminal: drop a
+/// byte order mark, turn CRLF line endings into LF, and strip trailing
+/// whitespace from every line and blank lines from the end. What is left ends
+/// with a single newline, unless it is empty.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let body = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut s = String::with_capacity(body.len() + 1);
+    for line in body.lines() {
+        s.push_str(line.trim_end());
+        s.push('\n');
+    }
+    let len = s.trim_end().len();
+    s.truncate(len);
+    if !s.is_empty() {
+        s.push('\n');
+    }
+    match s == input {
+        true => Cow::Borrowed(input),
+        false => Cow::Owned(s),
+    }
+}
+
+/// Why `input` looks cut off, if it does: puzzle inputs end with a newline,
+/// and in grids every line is as long as the first.
+pub fn truncation_warning(input: &str) -> Option<String> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    if input.is_empty() {
+        return Some("the input is empty".to_string());
+    }
+    if !input.ends_with('\n') {
+        return Some("the input does not end with a newline, so it may be cut off".to_string());
+    }
+    let lines: Vec<&str> = input.lines().map(|line| line.trim_end()).collect();
+    let width = lines[0].len();
+    let (last, rest) = lines.split_last()?;
+    if rest.len() >= 2 && width > 0 && rest.iter().all(|line| line.len() == width) && last.len() < width {
+        return Some(format!(
+            "the last line is {} characters long but the others are {}, so the input may be cut off",
+            last.len(),
+            width,
+        ));
+    }
+    None
+}
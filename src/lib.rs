@@ -0,0 +1,89 @@
+//! Advent of Code 2024 solutions.
+//!
+//! Each day is a module with `parse`, `solve_part1` and `solve_part2`, plus the
+//! types it models the puzzle with. `DAYS` has them all behind the `Solution` trait.
+
+mod answers;
+mod bench;
+mod cache;
+mod config;
+mod dir;
+mod generate;
+mod grid;
+mod image;
+mod input;
+mod parallel;
+mod params;
+mod parse;
+mod pos;
+mod render;
+mod scaffold;
+mod simulate;
+mod solution;
+mod trace;
+mod tui;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+
+pub use answers::{Answers, Verdict, ANSWERS_FILE};
+pub use bench::{bench_day, format_duration, Baseline, Stage, Stats, BASELINE_FILE};
+pub use cache::{checksum, Cache, CACHE_FILE};
+pub use config::{Config, CONFIG_FILE};
+pub use dir::Dir;
+pub use generate::{generator, Generator, GENERATORS};
+pub use grid::Grid;
+pub use image::{write_gif, Image, Palette, Rgb};
+pub use input::{normalize, truncation_warning};
+pub use parallel::set_threads;
+pub use params::Params;
+pub use parse::{ParseError, Source};
+pub use pos::Pos;
+pub use render::{Cell, Color, Frame, Frames, Player};
+pub use scaffold::scaffold;
+pub use simulate::{Cycle, Outcome, Simulation, States};
+pub use solution::{Answer, Day, Solution, SolveError};
+pub use trace::{Difference, Event, Events, Replay, Trace};
+pub use tui::{browse, Viewer};
+
+/// All solved days, in order. This is the only place a new day is wired in
+/// (`aoc new` does it for you).
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Lists>(1),
+    Day::new::<day02::Reports>(2),
+    Day::new::<day03::Memory>(3),
+    Day::new::<day04::WordSearch>(4),
+    Day::new::<day05::Manual>(5),
+    Day::new::<day06::World>(6),
+    Day::new::<day07::Equations>(7),
+    Day::new::<day08::Map>(8),
+    Day::new::<day09::DiskMap>(9),
+    Day::new::<day10::Map>(10),
+    Day::new::<day11::Arrangement>(11),
+    Day::new::<day12::Garden>(12),
+    Day::new::<day13::Arcade>(13),
+    Day::new::<day14::Robots>(14),
+    Day::new::<day15::Warehouse>(15),
+    Day::new::<day16::Maze>(16),
+    Day::new::<day17::Machine>(17),
+];
+
+/// Look up a registered day by its number.
+pub fn day(num: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.num == num)
+}
@@ -0,0 +1,1034 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use tracing::debug;
+use tracing_subscriber::EnvFilter;
+
+use aoc::{
+    Answer, Answers, Baseline, Cache, Config, Day, Event, Frames, Player, Replay, Solution, SolveError, Stage, Trace,
+    Verdict, ANSWERS_FILE, BASELINE_FILE, CACHE_FILE, CONFIG_FILE, DAYS,
+};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Config file with per-day puzzle parameters
+    #[arg(long, global = true, default_value = CONFIG_FILE)]
+    config: PathBuf,
+    /// Override a puzzle parameter for the selected days, e.g. "seconds=50"
+    #[arg(long = "param", global = true, value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+    /// Log what the solvers are doing: -v for progress, -vv for every step. The AOC_LOG
+    /// environment variable takes a finer filter instead, e.g. "aoc::day16=trace"
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Threads for the brute-force searches of the "parallel" feature (default: one per CPU)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(1..))]
+    threads: Option<u16>,
+}
+
+/// Environment variable with a tracing filter, which overrides -v.
+const LOG_ENV: &str = "AOC_LOG";
+
+fn init_tracing(verbose: u8) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_env(LOG_ENV).unwrap_or_else(|_| EnvFilter::new(level));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+fn parse_param(s: &str) -> Result<(String, i64), String> {
+    let (name, value) = s.split_once('=').ok_or_else(|| format!("expected NAME=VALUE: {}", s))?;
+    let value = value.parse().map_err(|_| format!("not an integer: {}", value))?;
+    Ok((name.to_string(), value))
+}
+
+/// A frame range as the first frame and one past the last, if any.
+fn parse_frames(s: &str) -> Result<(usize, Option<usize>), String> {
+    let num = |n: &str| n.parse::<usize>().map_err(|_| format!("not a frame number: {}", n));
+    let (start, end) = match s.split_once("..") {
+        None => (num(s)?, Some(num(s)? + 1)),
+        Some((start, end)) => {
+            let start = if start.is_empty() { 0 } else { num(start)? };
+            let end = match end.strip_prefix('=') {
+                Some(last) => Some(num(last)? + 1),
+                None if end.is_empty() => None,
+                None => Some(num(end)?),
+            };
+            (start, end)
+        },
+    };
+    match end {
+        Some(end) if end <= start => Err(format!("empty frame range: {}", s)),
+        _ => Ok((start, end)),
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day and print the answers, or all days and print a table of answers and timings
+    Run {
+        /// Day number, or "all"
+        day: DaySelection,
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read input from this file ("-" for stdin) instead of NN.input
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Solve every file in this directory and print a table of the results
+        #[arg(long, conflicts_with = "input")]
+        inputs: Option<PathBuf>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Play an animation of how the day is solved, for days that have one
+        #[arg(long, conflicts_with_all = ["inputs", "format"])]
+        visualize: bool,
+        /// Animation speed in frames per second; 0 waits for Enter before each frame
+        #[arg(long, default_value_t = 10.0, requires = "visualize")]
+        fps: f64,
+        /// Animate without colours
+        #[arg(long, requires = "visualize")]
+        no_color: bool,
+        /// Write the animation of --part to an image file instead (.ppm, or .png and .gif with the
+        /// "images" feature); several frames as PNG go to numbered files
+        #[arg(long, value_name = "FILE", requires = "part", conflicts_with_all = ["visualize", "inputs", "format"])]
+        out: Option<PathBuf>,
+        /// Only show or write these frames, e.g. "7000..7100", "7000..=7100", "50.." or "12"
+        #[arg(long, value_name = "RANGE", value_parser = parse_frames)]
+        frames: Option<(usize, Option<usize>)>,
+        /// Size in pixels of each cell in written images
+        #[arg(long, default_value_t = 4, requires = "out", value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+        /// Solve every part again rather than reuse answers cached in .aoc-cache
+        #[arg(long)]
+        no_cache: bool,
+        /// Known answers file, to check the table "all" prints against
+        #[arg(long, default_value = ANSWERS_FILE)]
+        answers: PathBuf,
+        /// Record each step of --part to a trace file, for days that simulate something
+        #[arg(
+            long,
+            value_name = "FILE",
+            requires = "part",
+            conflicts_with_all = ["visualize", "out", "inputs", "format"]
+        )]
+        trace: Option<PathBuf>,
+    },
+    /// Step forward and back through a recorded trace, or compare two
+    Replay {
+        /// Trace file written by "run --trace"
+        trace: PathBuf,
+        /// Show where this trace first differs from the other one
+        #[arg(long, value_name = "OTHER")]
+        diff: Option<PathBuf>,
+        /// Just print this event (counting from 0)
+        #[arg(long, conflicts_with = "diff")]
+        at: Option<usize>,
+    },
+    /// Solve one day (or all days) and check the answers against the known answers
+    Verify {
+        /// Day number, or "all"
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Known answers file
+        #[arg(long, default_value = ANSWERS_FILE)]
+        answers: PathBuf,
+    },
+    /// Print a random input for a day
+    Gen {
+        /// Day number
+        day: u8,
+        /// Random seed; the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Input size, e.g. grid width or number of lines (defaults to the real
+        /// input's, and is clamped to what the day supports)
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Time parsing and each part of one day (or all days) over several iterations
+    Bench {
+        /// Day number, or "all"
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Number of timed runs of each stage
+        #[arg(long, short = 'n', default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Baseline file to compare against
+        #[arg(long, default_value = BASELINE_FILE)]
+        baseline: PathBuf,
+        /// Record this run's medians in the baseline file
+        #[arg(long)]
+        save: bool,
+        /// Flag stages whose median is this many percent slower than the baseline
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Start a new day: its module from a template, registered in the day list, and empty input, example and
+    /// fuzz target files. Run from the repository root; an existing day is never overwritten
+    New {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Browse the days full screen: their answers and timings, and each day's visualizations, played with
+    /// keys to pause, step and change speed. Needs the "tui" feature
+    Browse {
+        /// Frames per second to start playing at
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+        /// Known answers file, to check the answers against
+        #[arg(long, default_value = ANSWERS_FILE)]
+        answers: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// "Part N: answer" lines
+    Text,
+    /// One JSON object per day, one per line
+    Json,
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    One(&'static Day),
+}
+
+impl std::str::FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+        let num: u8 = s.parse().map_err(|_| format!("not a day number: {}", s))?;
+        aoc::day(num).map(Self::One).ok_or_else(|| format!("day {} is not solved (yet)", num))
+    }
+}
+
+impl DaySelection {
+    fn days(self) -> Vec<&'static Day> {
+        match self {
+            Self::All => DAYS.iter().collect(),
+            Self::One(day) => vec![day],
+        }
+    }
+}
+
+fn read_input(day: &Day, path: &Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        },
+        Some(path) => fs::read_to_string(path),
+        None => fs::read_to_string(day.input_file()),
+    }
+}
+
+/// The outcome of solving one day, with the time taken by each stage.
+struct RunResult {
+    day: u8,
+    parse_time: Option<Duration>,
+    /// Each part's answer and how long it took, or `None` if it came from the cache
+    answers: Vec<(u8, Result<Answer, SolveError>, Option<Duration>)>,
+    /// Why the input could not be read or parsed
+    error: Option<String>,
+    /// Things about the input that look wrong but did not stop it parsing
+    warnings: Vec<String>,
+}
+
+impl RunResult {
+    fn failed(&self) -> bool {
+        self.error.is_some() || self.answers.iter().any(|(_, answer, _)| answer.is_err())
+    }
+
+    /// All errors, including those of each part, prefixed with the day.
+    fn errors(&self) -> Vec<String> {
+        let part_errors = self.answers.iter().filter_map(|(_, answer, _)| answer.as_ref().err());
+        self.error.iter()
+            .cloned()
+            .chain(part_errors.map(|e| format!("day {:02} {}", self.day, e)))
+            .collect()
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        let parts: Vec<_> = self.answers.iter()
+            .map(|(part, answer, time)| json!({
+                "part": part,
+                "answer": answer.as_ref().ok().map(serde_json::Value::from),
+                "time_ns": time.map(|time| time.as_nanos() as u64),
+                "cached": time.is_none(),
+            }))
+            .collect();
+        let warnings = self.warnings.iter().map(|w| json!({ "level": "warning", "message": w }));
+        let diagnostics: Vec<_> = self.errors().iter()
+            .map(|e| json!({ "level": "error", "message": e }))
+            .chain(warnings)
+            .collect();
+        json!({
+            "day": self.day,
+            "parse_time_ns": self.parse_time.map(|time| time.as_nanos() as u64),
+            "parts": parts,
+            "diagnostics": diagnostics,
+        })
+    }
+}
+
+/// Solve the selected parts of `day`, reusing answers from `cache` and
+/// adding new ones to it. Parsing is skipped if every answer is cached.
+fn run(
+    day: &Day,
+    part: Option<u8>,
+    input: &io::Result<String>,
+    config: &Config,
+    mut cache: Option<&mut Cache>,
+) -> RunResult {
+    let mut result = RunResult { day: day.num, parse_time: None, answers: vec![], error: None, warnings: vec![] };
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            result.error = Some(format!("day {:02}: cannot read input: {}", day.num, e));
+            return result;
+        },
+    };
+    if let Some(warning) = aoc::truncation_warning(input) {
+        result.warnings.push(format!("day {:02}: {}", day.num, warning));
+    }
+    let params = config.params(day.num);
+    let key = Cache::key(input, &params);
+    let parts: Vec<u8> = [1, 2].into_iter().filter(|&n| part.is_none_or(|p| p == n)).collect();
+    let cached: Option<Vec<Answer>> = parts.iter()
+        .map(|&n| cache.as_deref().and_then(|cache| cache.get(day, n, key)).cloned())
+        .collect();
+    if let Some(answers) = cached {
+        debug!(day = day.num, "cached");
+        result.answers = parts.into_iter().zip(answers).map(|(n, answer)| (n, Ok(answer), None)).collect();
+        return result;
+    }
+    let start = Instant::now();
+    let solution = match day.parse(input, &params) {
+        Ok(solution) => solution,
+        Err(e) => {
+            result.error = Some(e.to_string());
+            return result;
+        },
+    };
+    result.parse_time = Some(start.elapsed());
+    debug!(day = day.num, time = ?start.elapsed(), "parsed");
+    for n in parts {
+        if let Some(answer) = cache.as_deref().and_then(|cache| cache.get(day, n, key)) {
+            result.answers.push((n, Ok(answer.clone()), None));
+            continue;
+        }
+        let start = Instant::now();
+        let answer = solution.part(n);
+        debug!(day = day.num, part = n, time = ?start.elapsed(), "solved");
+        if let (Some(cache), Ok(answer)) = (cache.as_deref_mut(), &answer) {
+            cache.insert(day, n, key, answer.clone());
+        }
+        result.answers.push((n, answer, Some(start.elapsed())));
+    }
+    result
+}
+
+fn run_days(
+    days: &[&Day],
+    part: Option<u8>,
+    input: &Option<PathBuf>,
+    format: Format,
+    config: &Config,
+    mut cache: Option<&mut Cache>,
+) -> bool {
+    let mut all_ok = true;
+    for day in days.iter() {
+        let result = run(day, part, &read_input(day, input), config, cache.as_deref_mut());
+        match format {
+            Format::Text => {
+                for (n, answer, _) in result.answers.iter() {
+                    if let Ok(answer) = answer {
+                        println!("Part {}: {}", n, answer);
+                    }
+                }
+                for w in result.warnings.iter() {
+                    eprintln!("warning: {}", w);
+                }
+                for e in result.errors() {
+                    eprintln!("error: {}", e);
+                }
+            },
+            Format::Json => println!("{}", result.to_json()),
+        }
+        all_ok &= !result.failed();
+    }
+    all_ok
+}
+
+/// Solve every day and print a table of the answers, the time taken by each
+/// stage and how the answers compare with `answers`, then the total wall
+/// time. A day that fails is reported in its row and the rest still run.
+fn run_all(
+    part: Option<u8>,
+    input: &Option<PathBuf>,
+    config: &Config,
+    mut cache: Option<&mut Cache>,
+    answers: &Answers,
+) -> bool {
+    let start = Instant::now();
+    let mut all_ok = true;
+    let mut rows = vec![];
+    let mut mismatches = vec![];
+    for day in DAYS.iter() {
+        let result = run(day, part, &read_input(day, input), config, cache.as_deref_mut());
+        all_ok &= !result.failed();
+        let parse_time = match result.parse_time {
+            Some(time) => aoc::format_duration(time),
+            None if !result.answers.is_empty() => "cached".to_string(),
+            None => "-".to_string(),
+        };
+        let [(answer1, time1, check1), (answer2, time2, check2)] = [1, 2].map(|n| {
+            match (&result.error, result.answers.iter().find(|(p, _, _)| *p == n)) {
+                (_, Some((_, Ok(answer), time))) => {
+                    let check = match answers.verify(day.num, n, answer) {
+                        Verdict::Pass => "pass",
+                        Verdict::Unknown => "?",
+                        Verdict::Fail { expected } => {
+                            mismatches.push(format!("day {:02} part {}: expected {}", day.num, n, expected));
+                            all_ok = false;
+                            "FAIL"
+                        },
+                    };
+                    (answer.to_string(), time.map_or("cached".to_string(), aoc::format_duration), check)
+                },
+                (_, Some((_, Err(_), time))) => {
+                    ("error".to_string(), time.map_or("-".to_string(), aoc::format_duration), "error")
+                },
+                (Some(_), None) => ("error".to_string(), "-".to_string(), "error"),
+                (None, None) => ("-".to_string(), "-".to_string(), "-"),
+            }
+        });
+        rows.push([
+            format!("{:02}", day.num),
+            answer1,
+            answer2,
+            parse_time,
+            time1,
+            time2,
+            format!("{} {}", check1, check2),
+        ]);
+        for w in result.warnings.iter() {
+            eprintln!("warning: {}", w);
+        }
+        for e in result.errors() {
+            eprintln!("error: {}", e);
+        }
+    }
+    print_table(["Day", "Part 1", "Part 2", "Parse", "Part 1 time", "Part 2 time", "Check"], &rows);
+    for mismatch in mismatches.iter() {
+        println!("FAIL: {}", mismatch);
+    }
+    println!("Total: {} wall time", aoc::format_duration(start.elapsed()));
+    all_ok
+}
+
+fn load(day: &Day, input: &Option<PathBuf>, config: &Config) -> Result<Box<dyn Solution>, String> {
+    read_input(day, input)
+        .map_err(|e| format!("day {:02}: cannot read input: {}", day.num, e))
+        .and_then(|input| day.parse(&input, &config.params(day.num)).map_err(|e| e.to_string()))
+}
+
+/// Only the frames in `range`.
+fn select_frames(frames: Frames, range: Option<(usize, Option<usize>)>) -> Frames {
+    match range {
+        None => frames,
+        Some((start, None)) => Box::new(frames.skip(start)),
+        Some((start, Some(end))) => Box::new(frames.skip(start).take(end - start)),
+    }
+}
+
+/// Play the animation of each selected part, then print its answer.
+fn visualize_day(
+    day: &Day,
+    part: Option<u8>,
+    input: &Option<PathBuf>,
+    range: Option<(usize, Option<usize>)>,
+    player: &Player,
+    config: &Config,
+) -> bool {
+    let solution = match load(day, input, config) {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        },
+    };
+    let mut all_ok = true;
+    for n in [1, 2] {
+        if part.is_some_and(|p| p != n) {
+            continue;
+        }
+        match solution.visualize(n) {
+            Some(frames) => {
+                if let Err(e) = player.play(select_frames(frames, range), &mut io::stdout()) {
+                    eprintln!("error: {}", e);
+                    return false;
+                }
+            },
+            None => eprintln!("note: day {:02} part {} has no visualization", day.num, n),
+        }
+        match solution.part(n) {
+            Ok(answer) => println!("Part {}: {}", n, answer),
+            Err(e) => {
+                eprintln!("error: day {:02} {}", day.num, e);
+                all_ok = false;
+            },
+        }
+    }
+    all_ok
+}
+
+/// Write frames of the animation of `part` to `out`, in the format its
+/// extension names.
+fn export_frames(
+    solution: &dyn Solution,
+    part: u8,
+    range: Option<(usize, Option<usize>)>,
+    out: &Path,
+    scale: usize,
+    fps: f64,
+) -> Result<usize, Box<dyn Error>> {
+    let frames = solution.visualize(part).ok_or_else(|| format!("part {} has no visualization", part))?;
+    let palette = solution.palette();
+    let mut images = select_frames(frames, range).map(|frame| palette.paint(&frame, scale)).peekable();
+    if images.peek().is_none() {
+        return Err("no frames in that range".into());
+    }
+    let first = range.map_or(0, |(start, _)| start);
+    let single = range.is_some_and(|(start, end)| end == Some(start + 1));
+    Ok(match out.extension().and_then(|ext| ext.to_str()) {
+        Some("ppm") => {
+            let mut file = BufWriter::new(File::create(out)?);
+            let mut written = 0;
+            for image in images {
+                image.write_ppm(&mut file)?;
+                written += 1;
+            }
+            file.flush()?;
+            written
+        },
+        Some("png") if single => {
+            let image = images.next().ok_or("no frames to write")?;
+            image.write_png(BufWriter::new(File::create(out)?))?;
+            1
+        },
+        Some("png") => {
+            let stem = out.with_extension("");
+            let mut written = 0;
+            for (n, image) in images.enumerate() {
+                let path = PathBuf::from(format!("{}-{:05}.png", stem.display(), first + n));
+                image.write_png(BufWriter::new(File::create(path)?))?;
+                written += 1;
+            }
+            written
+        },
+        Some("gif") => {
+            let delay = (100.0 / fps).round().clamp(1.0, u16::MAX as f64) as u16;
+            aoc::write_gif(images, delay, BufWriter::new(File::create(out)?))?
+        },
+        _ => return Err("expected a .ppm, .png or .gif file".into()),
+    })
+}
+
+/// Print rows of cells in left-aligned columns under a header.
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(|cell| cell.chars().count());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let print_row = |cells: [&str; N]| {
+        let line: Vec<String> = cells.iter().zip(widths).map(|(cell, width)| format!("{:<1$}", cell, width)).collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+    print_row(header);
+    for row in rows.iter() {
+        print_row(row.each_ref().map(|cell| cell.as_str()));
+    }
+}
+
+/// Solve `day` for every file in `dir`, in name order. A file that fails to
+/// load is reported in its row and does not stop the others.
+fn run_batch(
+    day: &Day,
+    part: Option<u8>,
+    dir: &Path,
+    format: Format,
+    config: &Config,
+    mut cache: Option<&mut Cache>,
+) -> bool {
+    let mut paths: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .collect(),
+        Err(e) => {
+            eprintln!("error: {}: {}", dir.display(), e);
+            return false;
+        },
+    };
+    paths.sort();
+
+    let mut all_ok = true;
+    let mut rows = vec![];
+    let mut seen: HashMap<u64, String> = HashMap::new();
+    let mut duplicates = vec![];
+    for path in paths.iter() {
+        let name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy().to_string();
+        let input = fs::read_to_string(path);
+        let sum = input.as_ref().ok().map(|input| aoc::checksum(input));
+        let result = run(day, part, &input, config, cache.as_deref_mut());
+        all_ok &= !result.failed();
+        if let Some(sum) = sum {
+            match seen.get(&sum) {
+                Some(first) => duplicates.push((first.clone(), name.clone())),
+                None => { seen.insert(sum, name.clone()); },
+            }
+        }
+        if format == Format::Json {
+            let mut json = result.to_json();
+            json["file"] = name.into();
+            json["checksum"] = sum.map(|sum| format!("{:016x}", sum)).into();
+            println!("{}", json);
+            continue;
+        }
+        let answer = |n| match (&result.error, result.answers.iter().find(|(p, _, _)| *p == n)) {
+            (_, Some((_, Ok(answer), _))) => answer.to_string(),
+            (_, Some((_, Err(_), _))) => "error".to_string(),
+            (Some(_), None) if n == 1 => "error".to_string(),
+            _ => "-".to_string(),
+        };
+        let total = match result.parse_time {
+            Some(parse_time) => {
+                aoc::format_duration(parse_time + result.answers.iter().filter_map(|(_, _, time)| *time).sum())
+            },
+            None if !result.answers.is_empty() => "cached".to_string(),
+            None => "-".to_string(),
+        };
+        rows.push([
+            name,
+            sum.map_or("-".to_string(), |sum| format!("{:016x}", sum)),
+            answer(1),
+            answer(2),
+            total,
+        ]);
+        for w in result.warnings.iter() {
+            eprintln!("warning: {}: {}", path.display(), w);
+        }
+        for e in result.errors() {
+            eprintln!("error: {}: {}", path.display(), e);
+        }
+    }
+    if format == Format::Text {
+        print_table(["File", "Checksum", "Part 1", "Part 2", "Time"], &rows);
+        for (first, dup) in duplicates.iter() {
+            println!("note: {} is identical to {}", dup, first);
+        }
+    }
+    all_ok
+}
+
+/// Check each day's answers against `answers`, returning whether all
+/// known answers match. Days that fail to load are reported and skipped.
+fn verify_days(days: &[&Day], answers: &Answers, config: &Config) -> bool {
+    let mut all_ok = true;
+    for day in days.iter() {
+        let solution = read_input(day, &None)
+            .map_err(|e| format!("cannot read input: {}", e))
+            .and_then(|input| day.parse(&input, &config.params(day.num)).map_err(|e| e.to_string()));
+        let solution = match solution {
+            Ok(solution) => solution,
+            Err(e) => {
+                println!("Day {:02}: error: {}", day.num, e);
+                all_ok = false;
+                continue;
+            },
+        };
+        for part in [1, 2] {
+            let answer = match solution.part(part) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("Day {:02} part {}: error: {}", day.num, part, e.message);
+                    all_ok = false;
+                    continue;
+                },
+            };
+            match answers.verify(day.num, part, &answer) {
+                Verdict::Pass => println!("Day {:02} part {}: pass ({})", day.num, part, answer),
+                Verdict::Unknown => println!("Day {:02} part {}: unknown ({})", day.num, part, answer),
+                Verdict::Fail { expected } => {
+                    println!("Day {:02} part {}: FAIL (got {}, expected {})", day.num, part, answer, expected);
+                    all_ok = false;
+                },
+            }
+        }
+    }
+    all_ok
+}
+
+/// Benchmark each day, comparing medians with (and optionally recording them
+/// in) `baseline`. Returns whether every day ran without a regression.
+fn bench_days(
+    days: &[&Day],
+    iterations: u32,
+    baseline: &mut Baseline,
+    save: bool,
+    threshold: f64,
+    config: &Config,
+) -> bool {
+    let mut all_ok = true;
+    println!(
+        "{:<4} {:<6} {:>10} {:>10} {:>10} {:>10} {:>8}",
+        "Day", "Stage", "Min", "Median", "Max", "Baseline", "Change",
+    );
+    for day in days.iter() {
+        let stats = read_input(day, &None)
+            .map_err(|e| format!("cannot read input: {}", e))
+            .and_then(|input| {
+                aoc::bench_day(day, &input, &config.params(day.num), iterations as usize).map_err(|e| e.to_string())
+            });
+        let stats = match stats {
+            Ok(stats) => stats,
+            Err(e) => {
+                println!("{:02}   error: {}", day.num, e);
+                all_ok = false;
+                continue;
+            },
+        };
+        for (stage, stats) in Stage::ALL.into_iter().zip(stats) {
+            let (old, change) = match baseline.get(day.num, stage) {
+                Some(old) => {
+                    let change = 100.0 * (stats.median.as_secs_f64() / old.as_secs_f64().max(1e-9) - 1.0);
+                    (aoc::format_duration(old), Some(change))
+                },
+                None => ("-".to_string(), None),
+            };
+            let regressed = change.is_some_and(|change| change > threshold);
+            println!(
+                "{:02}   {:<6} {:>10} {:>10} {:>10} {:>10} {:>8}{}",
+                day.num,
+                stage.name(),
+                aoc::format_duration(stats.min),
+                aoc::format_duration(stats.median),
+                aoc::format_duration(stats.max),
+                old,
+                change.map_or("-".to_string(), |change| format!("{:+.1}%", change)),
+                if regressed { "  REGRESSION" } else { "" },
+            );
+            all_ok &= !regressed;
+            if save {
+                baseline.set(day.num, stage, stats.median);
+            }
+        }
+    }
+    all_ok
+}
+
+/// Record the events of `part` in a trace file, returning how many there were.
+fn record_trace(solution: &dyn Solution, day: &Day, part: u8, path: &Path) -> Result<usize, Box<dyn Error>> {
+    let events = solution.trace(part).ok_or_else(|| format!("day {} part {} has no trace", day.num, part))?;
+    let trace = Trace { day: day.num, part, events: events.collect() };
+    trace.save(path)?;
+    Ok(trace.events.len())
+}
+
+/// Print the events around the current one, marking it.
+fn show_event(replay: &Replay, trace: &Trace) {
+    let start = replay.index().saturating_sub(2);
+    for (i, event) in trace.events.iter().enumerate().skip(start).take(5) {
+        let marker = if i == replay.index() { '>' } else { ' ' };
+        println!("{} {:>6}  {}", marker, i, event);
+    }
+}
+
+/// Compare two traces, print one event, or step through a trace with
+/// commands read from stdin.
+fn replay(path: &Path, diff: Option<&Path>, at: Option<usize>) -> bool {
+    let trace = match Trace::load(path) {
+        Ok(trace) => trace,
+        Err(e) => {
+            eprintln!("error: {}: {}", path.display(), e);
+            return false;
+        },
+    };
+    if let Some(other_path) = diff {
+        let other = match Trace::load(other_path) {
+            Ok(other) => other,
+            Err(e) => {
+                eprintln!("error: {}: {}", other_path.display(), e);
+                return false;
+            },
+        };
+        if (trace.day, trace.part) != (other.day, other.part) {
+            println!(
+                "note: comparing day {:02} part {} with day {:02} part {}",
+                trace.day, trace.part, other.day, other.part,
+            );
+        }
+        return match trace.diff(&other) {
+            None => {
+                println!("The traces are the same ({} events)", trace.events.len());
+                true
+            },
+            Some(difference) => {
+                let show = |event: Option<Event>| {
+                    event.map_or("(end of trace)".to_string(), |event| event.to_string())
+                };
+                println!("The traces agree for {} events, then differ:", difference.index);
+                println!("- {}", show(difference.left));
+                println!("+ {}", show(difference.right));
+                false
+            },
+        };
+    }
+    if let Some(at) = at {
+        return match trace.events.get(at) {
+            Some(event) => {
+                println!("{}", event);
+                true
+            },
+            None => {
+                eprintln!("error: the trace has only {} events", trace.events.len());
+                false
+            },
+        };
+    }
+
+    println!("Day {:02} part {}: {} events", trace.day, trace.part, trace.events.len());
+    println!("Enter or n: next, p: previous, n N / p N: N steps, a number: go there,");
+    println!("/kind: next event of that kind, q: quit");
+    let mut replay = Replay::new(&trace);
+    show_event(&replay, &trace);
+    for line in io::stdin().lines() {
+        let Ok(line) = line else { break };
+        let (command, count) = match line.trim().split_once(' ') {
+            Some((command, count)) => (command, count.trim().parse().ok()),
+            None => (line.trim(), None),
+        };
+        match command {
+            "" | "n" => replay.forward(count.unwrap_or(1)),
+            "p" => replay.back(count.unwrap_or(1)),
+            "q" => break,
+            _ => match (command.strip_prefix('/'), command.parse()) {
+                (Some(kind), _) => {
+                    if !replay.next_of_kind(kind) {
+                        println!("no more {:?} events", kind);
+                    }
+                },
+                (None, Ok(index)) => replay.goto(index),
+                (None, Err(_)) => println!("unknown command: {}", command),
+            },
+        }
+        show_event(&replay, &trace);
+    }
+    true
+}
+
+/// Call `f` with the result cache, unless it is turned off, and save what it
+/// adds. A cache that cannot be read is reported and replaced.
+fn with_cache(no_cache: bool, f: impl FnOnce(Option<&mut Cache>) -> bool) -> bool {
+    if no_cache {
+        return f(None);
+    }
+    let path = Path::new(CACHE_FILE);
+    let mut cache = Cache::load(path).unwrap_or_else(|e| {
+        eprintln!("warning: {}: {}; starting a new cache", path.display(), e);
+        Cache::default()
+    });
+    let ok = f(Some(&mut cache));
+    if let Err(e) = cache.save(path) {
+        eprintln!("warning: {}: {}", path.display(), e);
+    }
+    ok
+}
+
+fn load_answers(path: &Path) -> Option<Answers> {
+    match Answers::load(path) {
+        Ok(answers) => Some(answers),
+        Err(e) => {
+            eprintln!("error: {}: {}", path.display(), e);
+            None
+        },
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
+    if let Some(Err(e)) = cli.threads.map(|threads| aoc::set_threads(threads as usize)) {
+        eprintln!("error: --threads: {}", e);
+        return ExitCode::FAILURE;
+    }
+    let mut config = match Config::load(&cli.config) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: {}: {}", cli.config.display(), e);
+            return ExitCode::FAILURE;
+        },
+    };
+    for (name, value) in cli.params.iter() {
+        if let Err(e) = config.set_override(name, *value) {
+            eprintln!("error: --param: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    let ok = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            inputs,
+            format,
+            visualize,
+            fps,
+            no_color,
+            out,
+            frames,
+            scale,
+            no_cache,
+            answers,
+            trace,
+        } => {
+            match (day, inputs, out) {
+                (DaySelection::One(day), None, None) if trace.is_some() => {
+                    let path = trace.unwrap();
+                    let written = load(day, &input, &config).map_err(Box::from).and_then(|solution| {
+                        record_trace(&*solution, day, part.unwrap_or(1), &path)
+                    });
+                    match written {
+                        Ok(n) => {
+                            println!("Wrote {} event{} to {}", n, if n == 1 { "" } else { "s" }, path.display());
+                            true
+                        },
+                        Err(e) => {
+                            eprintln!("error: {}: {}", path.display(), e);
+                            false
+                        },
+                    }
+                },
+                (DaySelection::One(day), None, None) if visualize => {
+                    let player = Player { fps: (fps > 0.0).then_some(fps), color: !no_color };
+                    visualize_day(day, part, &input, frames, &player, &config)
+                },
+                (DaySelection::One(day), None, Some(out)) => {
+                    let written = load(day, &input, &config).map_err(Box::from).and_then(|solution| {
+                        export_frames(&*solution, part.unwrap_or(1), frames, &out, scale as usize, fps)
+                    });
+                    match written {
+                        Ok(n) => {
+                            println!("Wrote {} frame{} to {}", n, if n == 1 { "" } else { "s" }, out.display());
+                            true
+                        },
+                        Err(e) => {
+                            eprintln!("error: {}: {}", out.display(), e);
+                            false
+                        },
+                    }
+                },
+                (DaySelection::All, None, out) if visualize || out.is_some() || trace.is_some() => {
+                    eprintln!("error: --visualize, --out and --trace need a single day");
+                    false
+                },
+                (DaySelection::All, None, _) if format == Format::Text => match load_answers(&answers) {
+                    Some(answers) => with_cache(no_cache, |cache| run_all(part, &input, &config, cache, &answers)),
+                    None => false,
+                },
+                (_, None, _) => with_cache(no_cache, |cache| run_days(&day.days(), part, &input, format, &config, cache)),
+                (DaySelection::One(day), Some(dir), _) => {
+                    with_cache(no_cache, |cache| run_batch(day, part, &dir, format, &config, cache))
+                },
+                (DaySelection::All, Some(_), _) => {
+                    eprintln!("error: --inputs needs a single day");
+                    false
+                },
+            }
+        },
+        Command::Verify { day, answers } => match load_answers(&answers) {
+            Some(answers) => verify_days(&day.days(), &answers, &config),
+            None => false,
+        },
+        Command::Gen { day, seed, size } => match aoc::generator(day) {
+            Some(generator) => {
+                print!("{}", generator.generate(seed, size.unwrap_or(generator.default_size)));
+                true
+            },
+            None => {
+                eprintln!("error: there is no input generator for day {}", day);
+                false
+            },
+        },
+        Command::Bench { day, iterations, baseline: path, save, threshold } => match Baseline::load(&path) {
+            Ok(mut baseline) => {
+                let ok = bench_days(&day.days(), iterations, &mut baseline, save, threshold, &config);
+                if let Err(e) = save.then(|| baseline.save(&path)).transpose() {
+                    eprintln!("error: {}: {}", path.display(), e);
+                }
+                ok
+            },
+            Err(e) => {
+                eprintln!("error: {}: {}", path.display(), e);
+                false
+            },
+        },
+        Command::Replay { trace, diff, at } => replay(&trace, diff.as_deref(), at),
+        Command::Browse { fps, answers } => match load_answers(&answers) {
+            Some(answers) => match aoc::browse(&config, &answers, fps) {
+                Ok(()) => true,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    false
+                },
+            },
+            None => false,
+        },
+        Command::New { day } => match aoc::scaffold(Path::new("."), day) {
+            Ok(written) => {
+                for path in written.iter() {
+                    println!("Wrote {}", path.strip_prefix(".").unwrap_or(path).display());
+                }
+                true
+            },
+            Err(e) => {
+                eprintln!("error: {}", e);
+                false
+            },
+        },
+    };
+    match ok {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,50 @@
+//! Independent pieces of work in brute-force searches, spread over a thread
+//! pool with the `parallel` feature and run in order without it. Either way
+//! the results are the same.
+
+use std::ops::Range;
+
+/// The items that satisfy `f`, in their original order.
+pub(crate) fn filter<T, F>(items: Vec<T>, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items.into_par_iter().filter(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.into_iter().filter(f).collect()
+    }
+}
+
+/// The first number in `range` that satisfies `f`.
+pub(crate) fn find_first<F>(range: Range<usize>, f: F) -> Option<usize>
+where
+    F: Fn(&usize) -> bool + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        range.into_par_iter().find_first(f)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        range.into_iter().find(f)
+    }
+}
+
+/// Use `threads` threads for parallel work, rather than one per CPU. Only
+/// takes effect before any parallel work has started.
+#[cfg(feature = "parallel")]
+pub fn set_threads(threads: usize) -> Result<(), String> {
+    rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn set_threads(_threads: usize) -> Result<(), String> {
+    Err("setting a thread count needs the \"parallel\" feature (cargo build --features parallel)".to_string())
+}
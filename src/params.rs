@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use crate::{ParseError, Source};
+
+/// Named numeric parameters for a puzzle, e.g. grid dimensions or step counts.
+/// Solutions fall back to the real puzzle's values for anything not set here.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: &str, value: i64) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Set every parameter from `other`, replacing existing values.
+    pub fn extend(&mut self, other: &Params) {
+        self.values.extend(other.values.iter().map(|(name, value)| (name.clone(), *value)));
+    }
+
+    pub fn get(&self, name: &str, default: i64) -> i64 {
+        self.values.get(name).copied().unwrap_or(default)
+    }
+
+    /// A parameter that cannot be negative, such as a count or a cost, as an
+    /// unsigned type. Other values are reported at the end of `src`.
+    pub fn get_nonneg<T: TryFrom<i64>>(&self, src: Source, name: &str, default: T) -> Result<T, ParseError> {
+        match self.values.get(name) {
+            None => Ok(default),
+            Some(&value) => T::try_from(value)
+                .map_err(|_| src.error_at_end(format!("the {} parameter cannot be negative, not {}", name, value))),
+        }
+    }
+
+    /// Every parameter that is set, in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.values.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+}
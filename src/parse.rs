@@ -0,0 +1,116 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error found while parsing puzzle input, pointing at the offending text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// The day whose parser failed, filled in by the registry.
+    pub day: Option<u8>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number, counted in characters.
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self { day: None, line, column, text: text.to_string(), message: message.into() }
+    }
+
+    pub fn for_day(self, day: u8) -> Self {
+        Self { day: Some(day), ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const MAX_TEXT: usize = 40;
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        match self.text.chars().count() {
+            0 => write!(f, " (at end of input)"),
+            n if n > MAX_TEXT => {
+                write!(f, ": {:?}...", self.text.chars().take(MAX_TEXT).collect::<String>())
+            },
+            _ => write!(f, ": {:?}", self.text),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The full text of an input, used to locate substrings of it when reporting
+/// parse errors. All `text` arguments must be slices into the same input.
+#[derive(Clone, Copy, Debug)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.input.lines()
+    }
+
+    /// The 1-based (line, column) where `text` starts within the input.
+    pub fn locate(&self, text: &str) -> (usize, usize) {
+        let start = self.input.as_ptr() as usize;
+        let mut offset = (text.as_ptr() as usize).saturating_sub(start).min(self.input.len());
+        while !self.input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &self.input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = match before.rfind('\n') {
+            Some(i) => before[i + 1..].chars().count() + 1,
+            None => before.chars().count() + 1,
+        };
+        (line, column)
+    }
+
+    pub fn error(&self, text: &str, message: impl Into<String>) -> ParseError {
+        let (line, column) = self.locate(text);
+        ParseError::new(line, column, text, message)
+    }
+
+    /// An error located just past the end of the input.
+    pub fn error_at_end(&self, message: impl Into<String>) -> ParseError {
+        self.error(&self.input[self.input.len()..], message)
+    }
+
+    pub fn num<T: FromStr>(&self, text: &str) -> Result<T, ParseError> {
+        text.parse().map_err(|_| self.error(text, "expected a number"))
+    }
+
+    pub fn split_once<'b>(&self, text: &'b str, delim: &str) -> Result<(&'b str, &'b str), ParseError> {
+        text.split_once(delim).ok_or_else(|| self.error(text, format!("expected {:?}", delim)))
+    }
+
+    /// The blocks of lines in `text` separated by blank lines, without their
+    /// line endings.
+    pub fn sections<'b>(&self, text: &'b str) -> impl Iterator<Item = &'b str> {
+        text.split("\n\n").map(|section| section.trim_matches('\n')).filter(|section| !section.is_empty())
+    }
+
+    /// The two sections of `text`, e.g. a map and a list of moves.
+    pub fn two_sections<'b>(&self, text: &'b str) -> Result<(&'b str, &'b str), ParseError> {
+        let mut sections = self.sections(text);
+        match (sections.next(), sections.next(), sections.next()) {
+            (Some(first), Some(second), None) => Ok((first, second)),
+            (_, _, Some(third)) => Err(self.error(third, "expected only two sections")),
+            _ => Err(self.error(text, "expected two sections separated by a blank line")),
+        }
+    }
+
+    pub fn strip_prefix<'b>(&self, text: &'b str, prefix: &str) -> Result<&'b str, ParseError> {
+        text.strip_prefix(prefix).ok_or_else(|| self.error(text, format!("expected {:?}", prefix)))
+    }
+}
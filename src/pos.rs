@@ -0,0 +1,83 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed position on a 2D grid, with `y` growing downwards and `x` growing
+/// rightwards. Ordering is row-major (first by `y`, then by `x`).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos {
+    pub y: i64,
+    pub x: i64,
+}
+
+impl Pos {
+    pub const ORIGIN: Self = Self { y: 0, x: 0 };
+
+    pub const fn new(y: i64, x: i64) -> Self {
+        Self { y, x }
+    }
+
+    /// The 4 orthogonally adjacent positions, in reading order.
+    pub fn adjacents(self) -> impl Iterator<Item = Self> {
+        [(-1, 0), (0, -1), (0, 1), (1, 0)]
+            .into_iter()
+            .map(move |(y, x)| self + Self { y, x })
+    }
+
+    /// The 8 surrounding positions (including diagonals), in reading order.
+    pub fn surrounding(self) -> impl Iterator<Item = Self> {
+        [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)]
+            .into_iter()
+            .map(move |(y, x)| self + Self { y, x })
+    }
+
+    /// Wrap this position into the area spanned by `ORIGIN..bounds`.
+    pub fn wrap(self, bounds: Self) -> Self {
+        Self {
+            y: self.y.rem_euclid(bounds.y),
+            x: self.x.rem_euclid(bounds.x),
+        }
+    }
+}
+
+impl Add for Pos {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { y: self.y + other.y, x: self.x + other.x }
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self { y: self.y - other.y, x: self.x - other.x }
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Pos {
+    type Output = Self;
+
+    fn mul(self, n: i64) -> Self {
+        Self { y: self.y * n, x: self.x * n }
+    }
+}
+
+impl Neg for Pos {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { y: -self.y, x: -self.x }
+    }
+}
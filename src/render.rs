@@ -0,0 +1,175 @@
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::{Grid, Pos};
+
+/// The colours a frame can highlight cells in, as the standard ANSI ones.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    /// The SGR code that sets this as the foreground colour.
+    fn ansi_code(self) -> u8 {
+        match self {
+            Self::Red => 91,
+            Self::Green => 92,
+            Self::Yellow => 93,
+            Self::Blue => 94,
+            Self::Magenta => 95,
+            Self::Cyan => 96,
+            Self::White => 97,
+            Self::Gray => 90,
+        }
+    }
+}
+
+/// One character on screen, in an optional colour.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Color>,
+}
+
+/// A picture of a grid at one point in a simulation: a base layer drawn from
+/// the grid, with overlays drawn on top and an optional caption below.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    pub caption: String,
+}
+
+/// A lazily generated sequence of frames.
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+impl Frame {
+    /// Draw each cell of `grid` as the character `f` gives for it.
+    pub fn new<T, F>(grid: &Grid<T>, mut f: F) -> Self
+    where
+        F: FnMut(Pos, &T) -> char,
+    {
+        let mut cells = grid.map(|_| Cell { ch: ' ', color: None });
+        for (pos, cell) in grid.iter() {
+            cells[pos].ch = f(pos, cell);
+        }
+        Self { cells, caption: String::new() }
+    }
+
+    /// A frame of the given size with every cell set to `ch`.
+    pub fn filled(size: Pos, ch: char) -> Self {
+        Self { cells: Grid::from_fn(size, |_| Cell { ch, color: None }), caption: String::new() }
+    }
+
+    /// A frame showing lines of text, as wide as the longest.
+    pub fn text(text: &str) -> Self {
+        let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let size = Pos { y: lines.len() as i64, x: width as i64 };
+        let cells = Grid::from_fn(size, |pos| {
+            let ch = lines[pos.y as usize].get(pos.x as usize).copied().unwrap_or(' ');
+            Cell { ch, color: None }
+        });
+        Self { cells, caption: String::new() }
+    }
+
+    pub fn with_caption(self, caption: impl Into<String>) -> Self {
+        Self { caption: caption.into(), ..self }
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Put `ch` at `pos` in `color`. Positions outside the frame are ignored.
+    pub fn draw(&mut self, pos: Pos, ch: char, color: Color) {
+        if let Some(cell) = self.cells.get_mut(pos) {
+            *cell = Cell { ch, color: Some(color) };
+        }
+    }
+
+    /// Colour the character already at `pos`.
+    pub fn highlight(&mut self, pos: Pos, color: Color) {
+        if let Some(cell) = self.cells.get_mut(pos) {
+            cell.color = Some(color);
+        }
+    }
+
+    /// The frame with ANSI escapes for its colours, only switching colour
+    /// where it changes.
+    pub fn ansi(&self) -> String {
+        let mut s = String::new();
+        for row in self.cells.rows() {
+            let mut color = None;
+            for cell in row {
+                if cell.color != color {
+                    match cell.color {
+                        Some(c) => s.push_str(&format!("\x1b[{}m", c.ansi_code())),
+                        None => s.push_str("\x1b[0m"),
+                    }
+                    color = cell.color;
+                }
+                s.push(cell.ch);
+            }
+            if color.is_some() {
+                s.push_str("\x1b[0m");
+            }
+            s.push('\n');
+        }
+        s.push_str(&self.caption);
+        s.push('\n');
+        s
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.cells.render(|_, cell| cell.ch))?;
+        writeln!(f, "{}", self.caption)
+    }
+}
+
+/// Plays frames in place on a terminal.
+pub struct Player {
+    /// Frames per second, or `None` to wait for Enter before each frame.
+    pub fps: Option<f64>,
+    pub color: bool,
+}
+
+impl Player {
+    /// Show each frame in turn over the previous one, returning how many
+    /// were shown.
+    pub fn play(&self, mut frames: Frames, out: &mut impl Write) -> io::Result<usize> {
+        let delay = self.fps.map(|fps| Duration::from_secs_f64(1.0 / fps));
+        let mut stdin = io::stdin().lock();
+        // Clear the screen and hide the cursor while playing
+        write!(out, "\x1b[2J\x1b[?25l")?;
+        let mut shown = 0;
+        let result = frames.try_for_each(|frame| {
+            let text = match self.color {
+                true => frame.ansi(),
+                false => frame.to_string(),
+            };
+            // Draw from the top left, clearing whatever is left below
+            write!(out, "\x1b[H{}\x1b[J", text)?;
+            out.flush()?;
+            shown += 1;
+            match delay {
+                Some(delay) => thread::sleep(delay),
+                None => { stdin.read_line(&mut String::new())?; },
+            }
+            Ok(())
+        });
+        write!(out, "\x1b[?25h")?;
+        result.map(|_| shown)
+    }
+}
@@ -0,0 +1,159 @@
+//! Scaffolding for a new day: its module, registration and empty input files.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The starting point for a day's module. `{DAY}` is replaced by the day
+/// number and `{NN}` by the same padded to two digits.
+const TEMPLATE: &str = r#"//! Day {DAY}: TODO.
+
+use crate::{Answer, Params, ParseError, Solution, SolveError, Source};
+
+/// The puzzle input, one entry per line.
+pub struct Puzzle {
+    lines: Vec<String>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &str, _params: &Params) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        if input.is_empty() {
+            return Err(src.error_at_end("expected some input"));
+        }
+        Ok(Self { lines: input.lines().map(str::to_string).collect() })
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(self.lines.len().into())
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Err(SolveError::new("not solved yet"))
+    }
+}
+
+/// Parse the puzzle input.
+pub fn parse(input: &str, params: &Params) -> Result<Puzzle, ParseError> {
+    Puzzle::from_input(input, params)
+}
+
+/// The answer to part 1.
+pub fn solve_part1(puzzle: &Puzzle) -> Result<Answer, SolveError> {
+    puzzle.part1()
+}
+
+/// The answer to part 2.
+pub fn solve_part2(puzzle: &Puzzle) -> Result<Answer, SolveError> {
+    puzzle.part2()
+}
+"#;
+
+const FUZZ_TEMPLATE: &str = r#"#![no_main]
+
+use aoc::Params;
+use libfuzzer_sys::fuzz_target;
+
+// Inputs the parser accepts must never make the solver panic
+fuzz_target!(|input: &str| {
+    if let Ok(solution) = aoc::day({DAY}).unwrap().parse(input, &Params::new()) {
+        let _ = solution.part1();
+        let _ = solution.part2();
+    }
+});
+"#;
+
+const FUZZ_BIN: &str = r#"
+[[bin]]
+name = "day{NN}"
+path = "fuzz_targets/day{NN}.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+fn fill(template: &str, num: u8) -> String {
+    template.replace("{DAY}", &num.to_string()).replace("{NN}", &format!("{:02}", num))
+}
+
+/// Add day `num` to the crate at `root`: write `src/dayNN.rs` from a template,
+/// register it in `src/lib.rs`, and create an empty input, example and fuzz
+/// target, plus a line of unknown answers. Files that already exist are left
+/// alone, but a day whose module exists is refused. Returns the files written.
+pub fn scaffold(root: &Path, num: u8) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&num) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("there is no day {}", num)));
+    }
+    let module = root.join(format!("src/day{:02}.rs", num));
+    let lib_path = root.join("src/lib.rs");
+    let lib = fs::read_to_string(&lib_path)?;
+    if module.exists() || lib.contains(&format!("pub mod day{:02};", num)) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("day {} already exists (src/day{:02}.rs)", num, num),
+        ));
+    }
+    let lib = register(&lib, num)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "cannot find the day list in src/lib.rs"))?;
+
+    let mut written = vec![];
+    let mut create = |path: PathBuf, contents: &str| -> io::Result<()> {
+        if !path.exists() {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, contents)?;
+            written.push(path);
+        }
+        Ok(())
+    };
+    create(module, &fill(TEMPLATE, num))?;
+    create(root.join(format!("{:02}.input", num)), "")?;
+    create(root.join(format!("tests/examples/{:02}.txt", num)), "")?;
+    if root.join("fuzz/fuzz_targets").is_dir() {
+        create(root.join(format!("fuzz/fuzz_targets/day{:02}.rs", num)), &fill(FUZZ_TEMPLATE, num))?;
+    }
+    fs::write(&lib_path, lib)?;
+    written.push(lib_path);
+
+    let answers_path = root.join(crate::ANSWERS_FILE);
+    let answers = match fs::read_to_string(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    let known = answers.lines().any(|line| line.split_whitespace().next() == Some(&format!("{:02}", num)));
+    if !known {
+        fs::write(&answers_path, format!("{}{:02} ? ?\n", answers, num))?;
+        written.push(answers_path);
+    }
+    let fuzz_manifest = root.join("fuzz/Cargo.toml");
+    if written.iter().any(|path| path.starts_with(root.join("fuzz"))) && fuzz_manifest.exists() {
+        let manifest = fs::read_to_string(&fuzz_manifest)?;
+        fs::write(&fuzz_manifest, manifest + &fill(FUZZ_BIN, num))?;
+        written.push(fuzz_manifest);
+    }
+    Ok(written)
+}
+
+/// `lib` with day `num` declared as a module and added to `DAYS`, keeping
+/// both in day order.
+fn register(lib: &str, num: u8) -> Option<String> {
+    let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+    let day_of = |line: &str, prefix: &str| {
+        line.strip_prefix(prefix).and_then(|rest| rest.get(..2)).and_then(|nn| nn.parse::<u8>().ok())
+    };
+    for (prefix, line) in [
+        ("pub mod day", format!("pub mod day{:02};", num)),
+        ("    Day::new::<day", format!("    Day::new::<day{:02}::Puzzle>({}),", num, num)),
+    ] {
+        let days: Vec<(usize, u8)> = lines.iter()
+            .enumerate()
+            .filter_map(|(i, line)| day_of(line, prefix).map(|day| (i, day)))
+            .collect();
+        let (first, _) = days.first()?;
+        let at = days.iter().rfind(|(_, day)| *day < num).map_or(*first, |(i, _)| i + 1);
+        lines.insert(at, line);
+    }
+    Some(lines.join("\n") + "\n")
+}
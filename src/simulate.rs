@@ -0,0 +1,158 @@
+//! Running a simulation step by step until it halts or comes back to a state
+//! it has been in before, after which it would repeat forever.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a simulation starts repeating: state number `start` comes back
+/// every `period` steps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+/// How a simulation ended.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome<S> {
+    /// There was no state after `last`, which was reached after `steps` steps.
+    Halted { last: S, steps: usize },
+    /// The states go round in a cycle.
+    Cycle(Cycle),
+    /// Still going after the step limit, at `last`.
+    GaveUp { last: S, steps: usize },
+}
+
+/// A simulation from `start`, where `step` gives the state after each one,
+/// or `None` once there is nothing more to do.
+///
+/// Repeats are spotted by remembering every state, or with `brent`, by
+/// Brent's algorithm, which keeps only two states but may run up to twice as
+/// far before noticing and then steps through again from the start to find
+/// where the cycle begins. `step` must give the same result for the same
+/// state either way.
+pub struct Simulation<S, F> {
+    start: S,
+    step: F,
+    brent: bool,
+    limit: Option<usize>,
+}
+
+impl<S, F> Simulation<S, F>
+where
+    S: Clone + Eq + Hash,
+    F: Fn(&S) -> Option<S>,
+{
+    pub fn new(start: S, step: F) -> Self {
+        Self { start, step, brent: false, limit: None }
+    }
+
+    /// Detect cycles with Brent's algorithm instead of remembering every state.
+    pub fn brent(self) -> Self {
+        Self { brent: true, ..self }
+    }
+
+    /// Give up after this many steps.
+    pub fn limit(self, steps: usize) -> Self {
+        Self { limit: Some(steps), ..self }
+    }
+
+    /// Every state from the start, ending with `Err` if they start to repeat.
+    /// Each state is seen once when remembering every state; with Brent's
+    /// algorithm, up to a lap of the cycle comes round again before the `Err`.
+    /// Ignores the step limit.
+    pub fn states(self) -> States<S, F> {
+        let seen = match self.brent {
+            true => Seen::Brent { tortoise: self.start.clone(), at: 0, power: 1 },
+            false => Seen::All(HashMap::new()),
+        };
+        States { next: Some(self.start.clone()), index: 0, seen, sim: self }
+    }
+
+    /// Step until the simulation halts, repeats or reaches the step limit.
+    pub fn run(self) -> Outcome<S> {
+        let limit = self.limit;
+        let (mut last, mut steps) = (self.start.clone(), 0);
+        for (n, state) in self.states().enumerate() {
+            match state {
+                Err(cycle) => return Outcome::Cycle(cycle),
+                Ok(_) if limit.is_some_and(|limit| n > limit) => return Outcome::GaveUp { last, steps },
+                Ok(state) => (last, steps) = (state, n),
+            }
+        }
+        Outcome::Halted { last, steps }
+    }
+
+    /// The state `n` steps on from `state`, if it does not halt first.
+    fn advance(&self, mut state: S, n: usize) -> Option<S> {
+        for _ in 0..n {
+            state = (self.step)(&state)?;
+        }
+        Some(state)
+    }
+
+    /// Where the cycle starts, given its period: step two states `period`
+    /// apart from the start until they meet.
+    fn cycle_start(&self, period: usize) -> usize {
+        let mut behind = self.start.clone();
+        let mut ahead = self.advance(self.start.clone(), period).expect("a cycle never halts");
+        let mut start = 0;
+        while behind != ahead {
+            behind = (self.step)(&behind).expect("a cycle never halts");
+            ahead = (self.step)(&ahead).expect("a cycle never halts");
+            start += 1;
+        }
+        start
+    }
+}
+
+enum Seen<S> {
+    /// The index of every state so far
+    All(HashMap<S, usize>),
+    /// The state at index `at`, which the following ones are compared with
+    /// until `power` steps have passed, when it moves on to the current one.
+    Brent { tortoise: S, at: usize, power: usize },
+}
+
+/// The states of a simulation, see [`Simulation::states`].
+pub struct States<S, F> {
+    sim: Simulation<S, F>,
+    next: Option<S>,
+    index: usize,
+    seen: Seen<S>,
+}
+
+impl<S, F> Iterator for States<S, F>
+where
+    S: Clone + Eq + Hash,
+    F: Fn(&S) -> Option<S>,
+{
+    type Item = Result<S, Cycle>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = self.next.take()?;
+        let index = self.index;
+        match &mut self.seen {
+            Seen::All(seen) => {
+                if let Some(&start) = seen.get(&state) {
+                    return Some(Err(Cycle { start, period: index - start }));
+                }
+                seen.insert(state.clone(), index);
+            },
+            Seen::Brent { tortoise, at, power } => {
+                if index > *at && state == *tortoise {
+                    let period = index - *at;
+                    return Some(Err(Cycle { start: self.sim.cycle_start(period), period }));
+                }
+                if index - *at == *power {
+                    *tortoise = state.clone();
+                    *at = index;
+                    *power *= 2;
+                }
+            },
+        }
+        self.next = (self.sim.step)(&state);
+        self.index += 1;
+        Some(Ok(state))
+    }
+}
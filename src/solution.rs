@@ -0,0 +1,195 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{normalize, Events, Frames, Palette, Params, ParseError};
+
+/// The answer to one part of a puzzle. Numbers are wide enough for any
+/// `i64`, `u64` or `usize` a day works in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Num(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Num(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Self::Num(n.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Self::Num(n.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Num(n as i128)
+    }
+}
+
+impl From<&Answer> for serde_json::Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            // Anything made from an i64 or u64 is a JSON number
+            Answer::Num(n) => {
+                serde_json::Number::from_i128(*n).map_or_else(|| Self::from(n.to_string()), Self::Number)
+            },
+            Answer::Text(s) => Self::from(s.as_str()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+/// A well-formed input that a part of the puzzle has no answer for, e.g. a
+/// maze with no way from start to end.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolveError {
+    /// The part that failed, filled in by `Solution::part`.
+    pub part: Option<u8>,
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { part: None, message: message.into() }
+    }
+
+    pub fn for_part(self, part: u8) -> Self {
+        Self { part: Some(part), ..self }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(part) = self.part {
+            write!(f, "part {}: ", part)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// A day's puzzle: parse the input once, then solve each part from it.
+///
+/// Parsing rejects anything the parts cannot handle, so they only fail for
+/// inputs that are well-formed but have no answer.
+pub trait Solution {
+    fn parse(input: &str, params: &Params) -> Result<Self, ParseError>
+    where
+        Self: Sized;
+
+    /// Parse `input` once [`normalize`] has tidied it up. Both [`Day::parse`]
+    /// and each day's `parse` function come through here.
+    fn from_input(input: &str, params: &Params) -> Result<Self, ParseError>
+    where
+        Self: Sized,
+    {
+        Self::parse(&normalize(input), params)
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError>;
+
+    fn part2(&self) -> Result<Answer, SolveError>;
+
+    fn part(&self, part: u8) -> Result<Answer, SolveError> {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => panic!("there is no part {}", part),
+        }
+        .map_err(|e| e.for_part(part))
+    }
+
+    /// Frames showing how `part` is solved, for days with something worth
+    /// watching.
+    fn visualize(&self, _part: u8) -> Option<Frames<'_>> {
+        None
+    }
+
+    /// A record of each step taken in solving `part`, for days that simulate
+    /// something.
+    fn trace(&self, _part: u8) -> Option<Events<'_>> {
+        None
+    }
+
+    /// The colours to draw this day's frames in as images.
+    fn palette(&self) -> Palette {
+        Palette::DEFAULT
+    }
+
+    /// The names of the parameters `parse` reads. Config files and overrides
+    /// may not set any others.
+    fn param_names() -> &'static [&'static str]
+    where
+        Self: Sized,
+    {
+        &[]
+    }
+
+    /// Identifies the answers this code gives in the result cache. Change it
+    /// whenever a fix could change an answer, so that old ones are not reused.
+    fn version() -> &'static str
+    where
+        Self: Sized,
+    {
+        "1"
+    }
+}
+
+type ParseFn = fn(&str, &Params) -> Result<Box<dyn Solution>, ParseError>;
+
+/// An entry in the registry of solved days.
+pub struct Day {
+    pub num: u8,
+    parse: ParseFn,
+    version: fn() -> &'static str,
+    param_names: fn() -> &'static [&'static str],
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(num: u8) -> Self {
+        Self {
+            num,
+            parse: |input, params| Ok(Box::new(S::from_input(input, params)?)),
+            version: S::version,
+            param_names: S::param_names,
+        }
+    }
+
+    /// Parse `input`, see [`Solution::from_input`].
+    pub fn parse(&self, input: &str, params: &Params) -> Result<Box<dyn Solution>, ParseError> {
+        (self.parse)(input, params).map_err(|e| e.for_day(self.num))
+    }
+
+    /// The version of the day's solution, see [`Solution::version`].
+    pub fn version(&self) -> &'static str {
+        (self.version)()
+    }
+
+    /// The parameters the day reads, see [`Solution::param_names`].
+    pub fn param_names(&self) -> &'static [&'static str] {
+        (self.param_names)()
+    }
+
+    /// The conventional name of this day's input file.
+    pub fn input_file(&self) -> String {
+        format!("{:02}.input", self.num)
+    }
+}
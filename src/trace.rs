@@ -0,0 +1,180 @@
+//! Recorded traces of simulations, to replay and compare when one goes wrong.
+//!
+//! A trace file starts with a `trace day=NN part=N` line, then has one event
+//! per line: a kind followed by `name=value` fields, e.g. `move dir=< robot=4,2`.
+//! Positions are written as `x,y`. Blank lines and `#` comments are ignored.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{ParseError, Pos, Source};
+
+/// One state transition, e.g. a guard's step, with named values describing it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    pub kind: String,
+    pub fields: Vec<(String, String)>,
+}
+
+/// A lazily generated sequence of events.
+pub type Events<'a> = Box<dyn Iterator<Item = Event> + 'a>;
+
+impl Event {
+    pub fn new(kind: &str) -> Self {
+        Self { kind: kind.to_string(), fields: vec![] }
+    }
+
+    /// Add a field. Its value must not contain whitespace.
+    pub fn with(mut self, name: &str, value: impl fmt::Display) -> Self {
+        let value = value.to_string();
+        assert!(!value.contains(char::is_whitespace), "trace values cannot contain spaces: {:?}", value);
+        self.fields.push((name.to_string(), value));
+        self
+    }
+
+    /// Add a field holding a position.
+    pub fn with_pos(self, name: &str, pos: Pos) -> Self {
+        self.with(name, format!("{},{}", pos.x, pos.y))
+    }
+
+    /// Add a field holding a list of positions.
+    pub fn with_positions(self, name: &str, positions: &[Pos]) -> Self {
+        let list: Vec<String> = positions.iter().map(|pos| format!("{},{}", pos.x, pos.y)).collect();
+        self.with(name, list.join(";"))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find(|(n, _)| n == name).map(|(_, value)| value.as_str())
+    }
+
+    fn parse(src: &Source, line: &str) -> Result<Self, ParseError> {
+        let mut words = line.split_whitespace();
+        let kind = words.next().ok_or_else(|| src.error(line, "expected an event"))?;
+        let fields = words
+            .map(|word| {
+                let (name, value) = src.split_once(word, "=")?;
+                Ok((name.to_string(), value.to_string()))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { kind: kind.to_string(), fields })
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        for (name, value) in self.fields.iter() {
+            write!(f, " {}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The events of one part of a day's solution, in order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace {
+    pub day: u8,
+    pub part: u8,
+    pub events: Vec<Event>,
+}
+
+/// Where two traces first disagree: the event each has there, if any.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Difference {
+    pub index: usize,
+    pub left: Option<Event>,
+    pub right: Option<Event>,
+}
+
+impl Trace {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let mut lines = input.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#'));
+        let line = lines.next().ok_or_else(|| src.error_at_end("expected a \"trace day=NN part=N\" line"))?;
+        let header = Event::parse(&src, line)?;
+        let field = |name| -> Result<u8, ParseError> {
+            let value = header.get(name).ok_or_else(|| src.error(line, format!("expected {}= in the header", name)))?;
+            src.num(value)
+        };
+        let (day, part) = match header.kind.as_str() {
+            "trace" => (field("day")?, field("part")?),
+            _ => return Err(src.error(line, "expected a \"trace day=NN part=N\" line")),
+        };
+        let events = lines.map(|line| Event::parse(&src, line)).collect::<Result<_, _>>()?;
+        Ok(Self { day, part, events })
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// The first event where the traces disagree, or `None` if they are the
+    /// same. One ending early disagrees with the other's next event.
+    pub fn diff(&self, other: &Trace) -> Option<Difference> {
+        let index = (0..self.events.len().max(other.events.len()))
+            .find(|i| self.events.get(*i) != other.events.get(*i))?;
+        Some(Difference { index, left: self.events.get(index).cloned(), right: other.events.get(index).cloned() })
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "trace day={:02} part={}", self.day, self.part)?;
+        for event in self.events.iter() {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+/// A position in a trace that can step forward and back through its events.
+pub struct Replay<'a> {
+    trace: &'a Trace,
+    index: usize,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(trace: &'a Trace) -> Self {
+        Self { trace, index: 0 }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The event at the current position, if the trace has any.
+    pub fn current(&self) -> Option<&'a Event> {
+        self.trace.events.get(self.index)
+    }
+
+    /// Step `n` events forward, stopping at the last.
+    pub fn forward(&mut self, n: usize) {
+        self.goto(self.index.saturating_add(n));
+    }
+
+    /// Step `n` events back, stopping at the first.
+    pub fn back(&mut self, n: usize) {
+        self.index = self.index.saturating_sub(n);
+    }
+
+    /// Go to event `index`, or the last if there are not that many.
+    pub fn goto(&mut self, index: usize) {
+        self.index = index.min(self.trace.events.len().saturating_sub(1));
+    }
+
+    /// Go to the next event after the current one of the given kind, if any.
+    pub fn next_of_kind(&mut self, kind: &str) -> bool {
+        let found = self.trace.events.iter().enumerate().skip(self.index + 1).find(|(_, e)| e.kind == kind);
+        if let Some((index, _)) = found {
+            self.index = index;
+        }
+        found.is_some()
+    }
+}
@@ -0,0 +1,537 @@
+//! A full-screen browser of the registered days: their answers and timings,
+//! and each day's visualizations, with keys to play, pause, step and change
+//! speed.
+//!
+//! The browser needs the `tui` feature. The [`Viewer`] it plays frames with
+//! does not.
+
+use std::collections::VecDeque;
+#[cfg(not(feature = "tui"))]
+use std::error::Error;
+use std::time::Duration;
+
+use crate::{Frame, Frames, Solution};
+
+/// How many frames the viewer keeps for stepping back. Going back further
+/// starts the animation again from the beginning.
+const HISTORY: usize = 64;
+
+const MIN_FPS: f64 = 0.5;
+const MAX_FPS: f64 = 960.0;
+
+/// One part's animation, which can step back as well as forward.
+pub struct Viewer<'a> {
+    solution: &'a dyn Solution,
+    part: u8,
+    frames: Frames<'a>,
+    /// The most recent frames generated, starting with frame number `first`
+    history: VecDeque<Frame>,
+    first: usize,
+    index: usize,
+    ended: bool,
+    pub playing: bool,
+    fps: f64,
+}
+
+impl<'a> Viewer<'a> {
+    /// The animation of `part` at its first frame, paused, or `None` if the
+    /// day has no visualization for it.
+    pub fn new(solution: &'a dyn Solution, part: u8, fps: f64) -> Option<Self> {
+        let frames = solution.visualize(part)?;
+        let fps = fps.clamp(MIN_FPS, MAX_FPS);
+        let mut viewer = Self {
+            solution,
+            part,
+            frames,
+            history: VecDeque::new(),
+            first: 0,
+            index: 0,
+            ended: false,
+            playing: false,
+            fps,
+        };
+        viewer.fill(0);
+        Some(viewer)
+    }
+
+    pub fn part(&self) -> u8 {
+        self.part
+    }
+
+    /// The number of the current frame, counting from 0.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The current frame, unless the animation has none at all.
+    pub fn current(&self) -> Option<&Frame> {
+        self.history.get(self.index - self.first)
+    }
+
+    /// The number of frames, once the animation has been played to the end.
+    pub fn frame_count(&self) -> Option<usize> {
+        self.ended.then_some(self.first + self.history.len())
+    }
+
+    /// Whether the current frame is the last.
+    pub fn at_end(&self) -> bool {
+        self.frame_count().is_some_and(|len| self.index + 1 >= len)
+    }
+
+    /// Step `n` frames forward, stopping at the last.
+    pub fn forward(&mut self, n: usize) {
+        self.goto(self.index.saturating_add(n));
+    }
+
+    /// Step `n` frames back, stopping at the first.
+    pub fn back(&mut self, n: usize) {
+        self.goto(self.index.saturating_sub(n));
+    }
+
+    /// Go to frame `index`, or the last if there are not that many.
+    pub fn goto(&mut self, index: usize) {
+        if index < self.first {
+            // Too far back to remember, so generate the frames again
+            self.frames = self.solution.visualize(self.part).expect("the part had a visualization before");
+            self.history.clear();
+            self.first = index.saturating_sub(HISTORY / 2);
+            self.ended = false;
+            for _ in 0..self.first {
+                self.frames.next();
+            }
+        }
+        self.fill(index);
+        self.index = index.min((self.first + self.history.len()).saturating_sub(1)).max(self.first);
+    }
+
+    /// Generate frames until frame `index` is in the history or there are no more.
+    fn fill(&mut self, index: usize) {
+        while !self.ended && self.first + self.history.len() <= index {
+            match self.frames.next() {
+                Some(frame) => {
+                    if self.history.len() == HISTORY {
+                        self.history.pop_front();
+                        self.first += 1;
+                    }
+                    self.history.push_back(frame);
+                },
+                None => self.ended = true,
+            }
+        }
+    }
+
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// Play twice as fast, up to a limit.
+    pub fn faster(&mut self) {
+        self.fps = (self.fps * 2.0).min(MAX_FPS);
+    }
+
+    /// Play half as fast, down to a limit.
+    pub fn slower(&mut self) {
+        self.fps = (self.fps / 2.0).max(MIN_FPS);
+    }
+
+    /// How long to show each frame while playing.
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+}
+
+#[cfg(feature = "tui")]
+pub use browser::browse;
+
+#[cfg(not(feature = "tui"))]
+pub fn browse(_config: &crate::Config, _answers: &crate::Answers, _fps: f64) -> Result<(), Box<dyn Error>> {
+    Err("the browser needs the \"tui\" feature (cargo build --features tui)".into())
+}
+
+#[cfg(feature = "tui")]
+mod browser {
+    use std::error::Error;
+    use std::fs;
+    use std::io::{self, Write};
+    use std::time::{Duration, Instant};
+
+    use crossterm::cursor::{Hide, MoveTo, Show};
+    use crossterm::event::{self, Event as TermEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+    use crossterm::style::{self, Attribute, Print, ResetColor, SetAttribute, SetForegroundColor};
+    use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+    use crossterm::{execute, queue};
+
+    use super::Viewer;
+    use crate::{format_duration, Answer, Answers, Color, Config, Day, Frame, Solution, SolveError, Verdict, DAYS};
+
+    /// Puts the terminal back as it was when dropped, even after an error.
+    struct Screen;
+
+    impl Screen {
+        fn enter() -> io::Result<Self> {
+            terminal::enable_raw_mode()?;
+            execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+            Ok(Self)
+        }
+    }
+
+    impl Drop for Screen {
+        fn drop(&mut self) {
+            let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+            let _ = terminal::disable_raw_mode();
+        }
+    }
+
+    /// What is known so far about one day, filled in a step at a time.
+    struct Row {
+        day: &'static Day,
+        solution: Option<Result<Box<dyn Solution>, String>>,
+        parse_time: Option<Duration>,
+        answers: [Option<(Result<Answer, SolveError>, Duration)>; 2],
+    }
+
+    impl Row {
+        /// Do the next piece of work for this day, returning false if there is none.
+        fn step(&mut self, config: &Config) -> bool {
+            let solution = match &self.solution {
+                None => {
+                    let start = Instant::now();
+                    self.solution = Some(match fs::read_to_string(self.day.input_file()) {
+                        Ok(input) => {
+                            self.day.parse(&input, &config.params(self.day.num)).map_err(|e| e.to_string())
+                        },
+                        Err(e) => Err(format!("cannot read {}: {}", self.day.input_file(), e)),
+                    });
+                    self.parse_time = Some(start.elapsed());
+                    return true;
+                },
+                Some(Err(_)) => return false,
+                Some(Ok(solution)) => solution,
+            };
+            let Some(n) = self.answers.iter().position(Option::is_none) else {
+                return false;
+            };
+            let start = Instant::now();
+            let answer = solution.part(n as u8 + 1);
+            self.answers[n] = Some((answer, start.elapsed()));
+            true
+        }
+
+        fn cells(&self, answers: &Answers) -> [String; 7] {
+            let pending = || "...".to_string();
+            let [(answer1, time1, check1), (answer2, time2, check2)] = [0, 1].map(|i| match &self.answers[i] {
+                Some((Ok(answer), time)) => {
+                    let check = match answers.verify(self.day.num, i as u8 + 1, answer) {
+                        Verdict::Pass => "pass",
+                        Verdict::Fail { .. } => "FAIL",
+                        Verdict::Unknown => "?",
+                    };
+                    (answer.to_string(), format_duration(*time), check)
+                },
+                Some((Err(_), time)) => ("error".to_string(), format_duration(*time), "error"),
+                None if matches!(self.solution, Some(Err(_))) => ("-".to_string(), "-".to_string(), "-"),
+                None => (pending(), pending(), ""),
+            });
+            let parse = match &self.solution {
+                Some(Err(e)) if e.starts_with("cannot read") => "no input".to_string(),
+                Some(Err(_)) => "error".to_string(),
+                Some(Ok(_)) => self.parse_time.map_or_else(pending, format_duration),
+                None => pending(),
+            };
+            let check = format!("{} {}", check1, check2).trim().to_string();
+            [format!("{:02}", self.day.num), answer1, answer2, parse, time1, time2, check]
+        }
+
+        /// The first error, to show under the table when the row is selected.
+        fn error(&self) -> Option<String> {
+            match &self.solution {
+                Some(Err(e)) => Some(e.clone()),
+                _ => {
+                    let mut errors = self.answers.iter().flatten().filter_map(|(answer, _)| answer.as_ref().err());
+                    errors.next().map(|e| e.to_string())
+                },
+            }
+        }
+    }
+
+    /// The key pressed, ignoring releases and repeats on terminals that report them.
+    fn read_key(timeout: Duration) -> io::Result<Option<KeyEvent>> {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+        Ok(match event::read()? {
+            TermEvent::Key(key) if key.kind != KeyEventKind::Release => Some(key),
+            _ => None,
+        })
+    }
+
+    fn is_quit(key: &KeyEvent) -> bool {
+        key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    fn term_color(color: Color) -> style::Color {
+        match color {
+            Color::Red => style::Color::Red,
+            Color::Green => style::Color::Green,
+            Color::Yellow => style::Color::Yellow,
+            Color::Blue => style::Color::Blue,
+            Color::Magenta => style::Color::Magenta,
+            Color::Cyan => style::Color::Cyan,
+            Color::White => style::Color::White,
+            Color::Gray => style::Color::DarkGrey,
+        }
+    }
+
+    /// Write `text` on line `y`, cut to the screen width.
+    fn line(out: &mut impl Write, y: u16, width: u16, text: &str) -> io::Result<()> {
+        let text: String = text.chars().take(width as usize).collect();
+        queue!(out, MoveTo(0, y), Print(text), Clear(ClearType::UntilNewLine))
+    }
+
+    /// Browse the days, solving them a piece at a time whenever no key is pressed.
+    pub fn browse(config: &Config, answers: &Answers, fps: f64) -> Result<(), Box<dyn Error>> {
+        let _screen = Screen::enter()?;
+        let mut out = io::stdout();
+        let mut rows: Vec<Row> = DAYS.iter()
+            .map(|day| Row { day, solution: None, parse_time: None, answers: [None, None] })
+            .collect();
+        let mut selected = 0;
+        let mut dirty = true;
+        loop {
+            if dirty {
+                draw_list(&mut out, &rows, selected, answers)?;
+            }
+            let busy = rows.iter().any(|row| {
+                row.answers.iter().any(Option::is_none) && !matches!(row.solution, Some(Err(_)))
+            });
+            let timeout = if busy { Duration::ZERO } else { Duration::from_secs(1) };
+            let Some(key) = read_key(timeout)? else {
+                // Nothing pressed, so solve a little more, starting with the selected day
+                let (before, after) = rows.split_at_mut(selected);
+                dirty = after.iter_mut().chain(before).any(|row| row.step(config));
+                continue;
+            };
+            dirty = true;
+            let part = match key.code {
+                _ if is_quit(&key) => return Ok(()),
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Up | KeyCode::Char('k') => {
+                    selected = selected.saturating_sub(1);
+                    continue;
+                },
+                KeyCode::Down | KeyCode::Char('j') => {
+                    selected = (selected + 1).min(rows.len() - 1);
+                    continue;
+                },
+                KeyCode::Home | KeyCode::Char('g') => {
+                    selected = 0;
+                    continue;
+                },
+                KeyCode::End | KeyCode::Char('G') => {
+                    selected = rows.len() - 1;
+                    continue;
+                },
+                KeyCode::Enter | KeyCode::Char('1') => 1,
+                KeyCode::Char('2') => 2,
+                _ => continue,
+            };
+            let row = &mut rows[selected];
+            if row.solution.is_none() {
+                row.step(config);
+            }
+            if let Some(Ok(solution)) = &row.solution {
+                if !view(&mut out, row.day, solution.as_ref(), part, fps)? {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn draw_list(out: &mut impl Write, rows: &[Row], selected: usize, answers: &Answers) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let header = ["Day", "Part 1", "Part 2", "Parse", "Part 1 time", "Part 2 time", "Check"].map(String::from);
+        let cells: Vec<[String; 7]> = rows.iter().map(|row| row.cells(answers)).collect();
+        let mut widths = header.each_ref().map(|cell| cell.chars().count());
+        for row in cells.iter() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let format_row = |row: &[String; 7]| {
+            let line: Vec<String> = row.iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<1$}", cell, width))
+                .collect();
+            format!("  {}", line.join("  "))
+        };
+        queue!(out, SetAttribute(Attribute::Bold))?;
+        line(out, 0, width, "Advent of Code 2024")?;
+        line(out, 1, width, "")?;
+        line(out, 2, width, &format_row(&header))?;
+        queue!(out, SetAttribute(Attribute::Reset))?;
+        // Keep the selected row on screen, leaving room for the headers and help
+        let visible = (height as usize).saturating_sub(7).max(1);
+        let skip = (selected + 1).saturating_sub(visible);
+        let mut y = 3;
+        for (i, row) in cells.iter().enumerate().skip(skip).take(visible) {
+            if i == selected {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            line(out, y, width, &format_row(row))?;
+            queue!(out, SetAttribute(Attribute::Reset))?;
+            y += 1;
+        }
+        line(out, y, width, "")?;
+        let error = rows[selected].error().map(|e| format!("  day {:02}: {}", rows[selected].day.num, e));
+        queue!(out, SetForegroundColor(style::Color::Red))?;
+        line(out, y + 1, width, &error.unwrap_or_default())?;
+        queue!(out, ResetColor, MoveTo(0, y + 2), Clear(ClearType::FromCursorDown))?;
+        line(out, height.saturating_sub(1), width, "  Up/Down: select  Enter or 1/2: show part 1/2  q: quit")?;
+        out.flush()
+    }
+
+    /// Play `part` of the day until the viewer goes back to the list,
+    /// returning false if it asked to quit altogether.
+    fn view(out: &mut impl Write, day: &Day, solution: &dyn Solution, part: u8, fps: f64) -> io::Result<bool> {
+        let Some(mut viewer) = Viewer::new(solution, part, fps) else {
+            let (width, height) = terminal::size()?;
+            line(out, height.saturating_sub(1), width, &format!(
+                "  Day {:02} part {} has no visualization (press any key)",
+                day.num,
+                part,
+            ))?;
+            out.flush()?;
+            while read_key(Duration::from_secs(60))?.is_none() {}
+            return Ok(true);
+        };
+        viewer.playing = true;
+        let mut offset = (0usize, 0usize);
+        let mut shown = Instant::now();
+        let mut dirty = true;
+        loop {
+            if dirty {
+                draw_view(out, day, &viewer, offset)?;
+                dirty = false;
+            }
+            let timeout = match viewer.playing {
+                true => viewer.delay().saturating_sub(shown.elapsed()),
+                false => Duration::from_secs(60),
+            };
+            let Some(key) = read_key(timeout)? else {
+                if viewer.playing && shown.elapsed() >= viewer.delay() {
+                    viewer.forward(1);
+                    viewer.playing = !viewer.at_end();
+                    shown = Instant::now();
+                    dirty = true;
+                }
+                continue;
+            };
+            dirty = true;
+            match key.code {
+                _ if is_quit(&key) => return Ok(false),
+                KeyCode::Char('q') => return Ok(false),
+                KeyCode::Esc | KeyCode::Backspace => return Ok(true),
+                KeyCode::Char(' ') => {
+                    if viewer.at_end() {
+                        viewer.goto(0);
+                    }
+                    viewer.playing = !viewer.playing;
+                    shown = Instant::now();
+                },
+                KeyCode::Right | KeyCode::Char('.') => {
+                    viewer.playing = false;
+                    viewer.forward(1);
+                },
+                KeyCode::Left | KeyCode::Char(',') => {
+                    viewer.playing = false;
+                    viewer.back(1);
+                },
+                KeyCode::PageDown => viewer.forward(10),
+                KeyCode::PageUp => viewer.back(10),
+                KeyCode::Home => viewer.goto(0),
+                KeyCode::Char('+') | KeyCode::Char('=') => viewer.faster(),
+                KeyCode::Char('-') => viewer.slower(),
+                KeyCode::Tab => {
+                    let other = 3 - viewer.part();
+                    if let Some(new) = Viewer::new(solution, other, viewer.fps()) {
+                        viewer = new;
+                        viewer.playing = true;
+                        offset = (0, 0);
+                    }
+                },
+                KeyCode::Char('h') => offset.1 = offset.1.saturating_sub(8),
+                KeyCode::Char('l') => offset.1 += 8,
+                KeyCode::Char('k') => offset.0 = offset.0.saturating_sub(4),
+                KeyCode::Char('j') => offset.0 += 4,
+                _ => dirty = false,
+            }
+        }
+    }
+
+    fn draw_view(out: &mut impl Write, day: &Day, viewer: &Viewer, offset: (usize, usize)) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let frames = match viewer.frame_count() {
+            Some(len) => format!("frame {} of {}", viewer.index() + 1, len),
+            None => format!("frame {}", viewer.index() + 1),
+        };
+        let state = if viewer.playing { "playing" } else { "paused" };
+        queue!(out, SetAttribute(Attribute::Bold))?;
+        line(out, 0, width, &format!(
+            "Day {:02} part {}  {}  {} at {} fps",
+            day.num,
+            viewer.part(),
+            frames,
+            state,
+            viewer.fps(),
+        ))?;
+        queue!(out, SetAttribute(Attribute::Reset))?;
+        let rows = height.saturating_sub(4);
+        match viewer.current() {
+            Some(frame) => {
+                draw_frame(out, frame, 1, rows, width, offset)?;
+                line(out, rows + 1, width, &frame.caption)?;
+            },
+            None => {
+                queue!(out, MoveTo(0, 1), Clear(ClearType::FromCursorDown))?;
+                line(out, 1, width, "(no frames)")?;
+            },
+        }
+        line(out, height.saturating_sub(1), width, concat!(
+            "Space: play/pause  Left/Right: step  PgUp/PgDn: 10 steps  Home: start  +/-: speed  ",
+            "h/j/k/l: pan  Tab: other part  Esc: back  q: quit",
+        ))?;
+        out.flush()
+    }
+
+    /// Draw the part of `frame` from `offset` that fits in `rows` lines from `top`.
+    fn draw_frame(
+        out: &mut impl Write,
+        frame: &Frame,
+        top: u16,
+        rows: u16,
+        width: u16,
+        offset: (usize, usize),
+    ) -> io::Result<()> {
+        let mut lines = frame.cells().rows().skip(offset.0);
+        for y in 0..rows {
+            queue!(out, MoveTo(0, top + y))?;
+            let Some(cells) = lines.next() else {
+                queue!(out, Clear(ClearType::FromCursorDown))?;
+                break;
+            };
+            let mut color = None;
+            for cell in cells.iter().skip(offset.1).take(width as usize) {
+                if cell.color != color {
+                    match cell.color {
+                        Some(c) => queue!(out, SetForegroundColor(term_color(c)))?,
+                        None => queue!(out, ResetColor)?,
+                    }
+                    color = cell.color;
+                }
+                queue!(out, Print(cell.ch))?;
+            }
+            queue!(out, ResetColor, Clear(ClearType::UntilNewLine))?;
+        }
+        Ok(())
+    }
+}
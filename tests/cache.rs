@@ -0,0 +1,59 @@
+//! Checks that cached answers are only reused for the same input, parameters and solution version.
+
+use aoc::{Answer, Cache, Day, Params, ParseError, Solution, SolveError};
+
+/// A day whose solution has been fixed since day 1's answers were cached.
+struct Fixed;
+
+impl Solution for Fixed {
+    fn parse(_input: &str, _params: &Params) -> Result<Self, ParseError> {
+        Ok(Self)
+    }
+
+    fn part1(&self) -> Result<Answer, SolveError> {
+        Ok(Answer::Num(2))
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        Ok(Answer::Num(2))
+    }
+
+    fn version() -> &'static str {
+        "2"
+    }
+}
+
+#[test]
+fn keys() {
+    let mut cache = Cache::default();
+    let key = Cache::key("3   4\n", &Params::new());
+    cache.insert(aoc::day(1).unwrap(), 1, key, Answer::Num(1));
+    assert_eq!(cache.get(aoc::day(1).unwrap(), 1, key), Some(&Answer::Num(1)));
+    assert_eq!(cache.get(aoc::day(1).unwrap(), 2, key), None);
+    assert_eq!(cache.get(aoc::day(2).unwrap(), 1, key), None);
+    assert_ne!(Cache::key("3   4\n", &Params::new().with("seconds", 5)), key);
+    assert_ne!(Cache::key("3   5\n", &Params::new()), key);
+
+    let fixed = Day::new::<Fixed>(1);
+    assert_eq!(cache.get(&fixed, 1, key), None);
+    cache.insert(&fixed, 2, key, Answer::Num(2));
+    assert_eq!(cache.get(aoc::day(1).unwrap(), 1, key), None);
+}
+
+#[test]
+fn round_trip() {
+    let output = Answer::Text("4,6,3,5,6,3,5,2,1,0".to_string());
+    let mut cache = Cache::default();
+    cache.insert(aoc::day(11).unwrap(), 2, 0xabc, Answer::Num(65601038650482));
+    cache.insert(aoc::day(17).unwrap(), 1, 0xdef, output.clone());
+    // A program that outputs a single value still gives text, not a number
+    cache.insert(aoc::day(17).unwrap(), 1, 0x123, Answer::Text("4".to_string()));
+    let loaded = Cache::parse(&cache.to_string()).unwrap();
+    assert_eq!(loaded.get(aoc::day(11).unwrap(), 2, 0xabc), Some(&Answer::Num(65601038650482)));
+    assert_eq!(loaded.get(aoc::day(17).unwrap(), 1, 0xdef), Some(&output));
+    assert_eq!(loaded.get(aoc::day(17).unwrap(), 1, 0x123), Some(&Answer::Text("4".to_string())));
+    assert!(Cache::parse("01 1 1 nothex n 5\n").is_err());
+    assert!(Cache::parse("01 1 1 abc x 5\n").is_err());
+    assert!(Cache::parse("01 1 1 abc n five\n").is_err());
+    assert!(Cache::parse("01 1 1 abc 5\n").is_err());
+}
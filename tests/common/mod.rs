@@ -0,0 +1,10 @@
+//! Helpers shared by the integration tests.
+
+use std::fs;
+use std::path::Path;
+
+/// The contents of `tests/examples/<name>`.
+pub fn example(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples").join(name);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
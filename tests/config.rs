@@ -0,0 +1,52 @@
+//! Checks that per-day config sections and overrides reach the solutions.
+
+mod common;
+
+use aoc::Config;
+
+use common::example;
+
+#[test]
+fn day_sections() {
+    let config = Config::parse("[day14]\nheight = 7\nwidth = 11\n\n[day16]\nturn_cost = 0\n").unwrap();
+    assert_eq!(config.params(14).get("height", 103), 7);
+    assert_eq!(config.params(14).get("seconds", 100), 100);
+    assert_eq!(config.params(16).get("height", 103), 103);
+}
+
+#[test]
+fn overrides() {
+    let mut config = Config::parse("[day14]\nseconds = 50\n").unwrap();
+    config.set_override("seconds", 10).unwrap();
+    assert_eq!(config.params(14).get("seconds", 100), 10);
+    // Only days that read a parameter get it
+    assert_eq!(config.params(11).get("seconds", 100), 100);
+    let e = config.set_override("heigth", 7).unwrap_err();
+    assert!(e.starts_with("no day has a parameter named heigth"), "{}", e);
+}
+
+#[test]
+fn errors() {
+    let e = Config::parse("[day14]\nheight = 7\nwidth = \"x\"\n").unwrap_err();
+    assert_eq!((e.line, e.column), (3, 1));
+    let e = Config::parse("[fourteen]\nheight = 7\n").unwrap_err();
+    assert_eq!((e.line, e.column), (1, 2));
+    assert!(Config::parse("[day14\n").is_err());
+    let e = Config::parse("[day14]\nheigth = 7\n").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 1));
+    assert_eq!(e.message, "day 14 has no parameter named heigth (it has: height, width, seconds)");
+    let e = Config::parse("[day01]\nseconds = 7\n").unwrap_err();
+    assert_eq!(e.message, "day 1 has no parameters, so cannot set seconds");
+}
+
+#[test]
+fn negative_params() {
+    for (day, name) in [(11, "part1_blinks"), (13, "a_cost"), (16, "turn_cost")] {
+        let input = example(&format!("{}.txt", day));
+        let config = Config::parse(&format!("[day{}]\n{} = -1\n", day, name)).unwrap();
+        let Err(e) = aoc::day(day).unwrap().parse(&input, &config.params(day)) else {
+            panic!("day {} accepted {} = -1", day, name);
+        };
+        assert_eq!(e.message, format!("the {} parameter cannot be negative, not -1", name));
+    }
+}
@@ -0,0 +1,129 @@
+//! Checks every day against the examples given in the puzzle descriptions.
+
+mod common;
+
+use aoc::{Params, Solution};
+
+use common::example;
+
+fn solve(day: u8, name: &str, params: &Params) -> Box<dyn Solution> {
+    let input = example(name);
+    aoc::day(day)
+        .unwrap_or_else(|| panic!("day {} is not registered", day))
+        .parse(&input, params)
+        .unwrap_or_else(|e| panic!("{}: {}", name, e))
+}
+
+fn check(day: u8, example: &str, part1: &str, part2: &str) {
+    let solution = solve(day, example, &Params::new());
+    assert_eq!(solution.part1().unwrap().to_string(), part1, "day {} part 1 ({})", day, example);
+    assert_eq!(solution.part2().unwrap().to_string(), part2, "day {} part 2 ({})", day, example);
+}
+
+fn check_part1(day: u8, example: &str, params: &Params, part1: &str) {
+    let solution = solve(day, example, params);
+    assert_eq!(solution.part1().unwrap().to_string(), part1, "day {} part 1 ({})", day, example);
+}
+
+fn check_part2(day: u8, example: &str, params: &Params, part2: &str) {
+    let solution = solve(day, example, params);
+    assert_eq!(solution.part2().unwrap().to_string(), part2, "day {} part 2 ({})", day, example);
+}
+
+#[test]
+fn day01() {
+    check(1, "01.txt", "11", "31");
+}
+
+#[test]
+fn day02() {
+    check(2, "02.txt", "2", "4");
+}
+
+#[test]
+fn day03() {
+    check_part1(3, "03.txt", &Params::new(), "161");
+    check_part2(3, "03-2.txt", &Params::new(), "48");
+}
+
+#[test]
+fn day04() {
+    check(4, "04.txt", "18", "9");
+}
+
+#[test]
+fn day05() {
+    check(5, "05.txt", "143", "123");
+}
+
+#[test]
+fn day06() {
+    check(6, "06.txt", "41", "6");
+}
+
+#[test]
+fn day07() {
+    check(7, "07.txt", "3749", "11387");
+}
+
+#[test]
+fn day08() {
+    check(8, "08.txt", "14", "34");
+    check_part2(8, "08-2.txt", &Params::new(), "9");
+}
+
+#[test]
+fn day09() {
+    check(9, "09.txt", "1928", "2858");
+}
+
+#[test]
+fn day10() {
+    check(10, "10.txt", "36", "81");
+}
+
+#[test]
+fn day11() {
+    check_part1(11, "11.txt", &Params::new().with("part1_blinks", 6), "22");
+    check_part1(11, "11.txt", &Params::new(), "55312");
+}
+
+#[test]
+fn day12() {
+    check(12, "12.txt", "140", "80");
+    check(12, "12-2.txt", "772", "436");
+    check(12, "12-3.txt", "1930", "1206");
+    check_part2(12, "12-4.txt", &Params::new(), "236");
+    check_part2(12, "12-5.txt", &Params::new(), "368");
+}
+
+#[test]
+fn day13() {
+    check_part1(13, "13.txt", &Params::new(), "480");
+}
+
+#[test]
+fn day14() {
+    // The example uses a smaller room, and has no Christmas tree for part 2
+    let params = Params::new().with("height", 7).with("width", 11);
+    check_part1(14, "14.txt", &params, "12");
+}
+
+#[test]
+fn day15() {
+    check(15, "15.txt", "10092", "9021");
+    check_part1(15, "15-2.txt", &Params::new(), "2028");
+}
+
+#[test]
+fn day16() {
+    check(16, "16.txt", "7036", "45");
+    check(16, "16-2.txt", "11048", "64");
+}
+
+#[test]
+fn day17() {
+    // Part 2 only makes sense for programs that can output themselves
+    check_part1(17, "17.txt", &Params::new(), "4,6,3,5,6,3,5,2,1,0");
+    check_part2(17, "17-2.txt", &Params::new(), "117440");
+}
@@ -0,0 +1,58 @@
+//! Checks that generated inputs are deterministic and solvable.
+
+use aoc::{Params, GENERATORS};
+
+#[test]
+fn same_seed_same_input() {
+    for generator in GENERATORS.iter() {
+        assert_eq!(generator.generate(7, 20), generator.generate(7, 20), "day {}", generator.num);
+        assert_ne!(generator.generate(7, 20), generator.generate(8, 20), "day {}", generator.num);
+    }
+}
+
+#[test]
+fn inputs_solve() {
+    for generator in GENERATORS.iter() {
+        let day = aoc::day(generator.num).unwrap();
+        for seed in 0..4 {
+            let size = if generator.num == 17 { 2 } else { 20 };
+            let input = generator.generate(seed, size);
+            let solution = day.parse(&input, &Params::new())
+                .unwrap_or_else(|e| panic!("seed {}: {}\n{}", seed, e, input));
+            solution.part1().unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+            // Random programs rarely output themselves, so there is no part 2
+            if generator.num != 17 {
+                solution.part2().unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+            }
+        }
+    }
+}
+
+#[test]
+fn boundary_sizes() {
+    for generator in GENERATORS.iter() {
+        let day = aoc::day(generator.num).unwrap();
+        let (min, max) = (*generator.sizes.start(), *generator.sizes.end());
+        let mut sizes = vec![0, min, min + 1];
+        if max != usize::MAX {
+            sizes.extend([max, max + 1]);
+        }
+        for size in sizes {
+            let input = generator.generate(1, size);
+            let solution = day.parse(&input, &Params::new())
+                .unwrap_or_else(|e| panic!("day {} size {}: {}\n{}", generator.num, size, e, input));
+            solution.part1().unwrap_or_else(|e| panic!("day {} size {}: {}", generator.num, size, e));
+        }
+    }
+    // Programs get no longer past the largest size, and part 2 can still try them
+    let day17 = aoc::generator(17).unwrap();
+    let max = *day17.sizes.end();
+    assert_eq!(day17.generate(5, max), day17.generate(5, max + 10));
+    for seed in 0..4 {
+        let input = day17.generate(seed, max);
+        let solution = aoc::day(17).unwrap().parse(&input, &Params::new()).unwrap();
+        if let Err(e) = solution.part2() {
+            assert!(!e.to_string().contains("not supported"), "seed {}: {}\n{}", seed, e, input);
+        }
+    }
+}
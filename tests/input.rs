@@ -0,0 +1,49 @@
+//! Inputs saved with Windows line endings, a byte order mark or stray whitespace parse like clean ones.
+
+mod common;
+
+use aoc::Params;
+
+use common::example;
+
+/// The example as a careless editor might save it.
+fn mangle(input: &str) -> String {
+    let lines: Vec<String> = input.lines().map(|line| format!("{} \t", line)).collect();
+    format!("\u{feff}{}\r\n\r\n", lines.join("\r\n"))
+}
+
+fn answers(day: u8, input: &str) -> Vec<String> {
+    let solution = aoc::day(day).unwrap().parse(input, &Params::new()).unwrap();
+    [1, 2].into_iter().map(|n| solution.part(n).unwrap().to_string()).collect()
+}
+
+#[test]
+fn mangled_examples() {
+    for (day, name) in [(1, "01.txt"), (5, "05.txt"), (13, "13.txt"), (15, "15.txt"), (17, "17-2.txt")] {
+        let input = example(name);
+        assert_eq!(answers(day, &mangle(&input)), answers(day, &input), "day {}", day);
+    }
+    // The days' own parse functions tidy up inputs too
+    assert!(aoc::day13::parse(&mangle(&example("13.txt")), &Params::new()).is_ok());
+}
+
+#[test]
+fn day01_any_spacing() {
+    assert_eq!(answers(1, "3 4\n4  3\n2\t5\n"), answers(1, "3   4\n4   3\n2   5\n"));
+}
+
+#[test]
+fn normalize() {
+    assert_eq!(aoc::normalize("a\r\nb  \r\n\r\n"), "a\nb\n");
+    assert_eq!(aoc::normalize("\u{feff}a"), "a\n");
+    assert_eq!(aoc::normalize(" \n\n"), "");
+    assert!(matches!(aoc::normalize("a\n\nb\n"), std::borrow::Cow::Borrowed(_)));
+}
+
+#[test]
+fn truncation_warnings() {
+    assert_eq!(aoc::truncation_warning("...\n...\n"), None);
+    assert!(aoc::truncation_warning("").is_some());
+    assert!(aoc::truncation_warning("1 2\n3 4").is_some());
+    assert!(aoc::truncation_warning("....\n....\n....\n..\n").is_some());
+}
@@ -0,0 +1,57 @@
+//! Checks that the days' own types and functions can be used directly.
+
+mod common;
+
+use aoc::{day12, day14, day16, day17, Answer, Cycle, Outcome, Params};
+
+use common::example;
+
+#[test]
+fn parse_and_solve() {
+    let lists = aoc::day01::parse(&example("01.txt"), &Params::new()).unwrap();
+    assert_eq!(aoc::day01::solve_part1(&lists), Ok(Answer::Num(11)));
+    assert_eq!(aoc::day01::solve_part2(&lists), Ok(Answer::Num(31)));
+}
+
+#[test]
+fn maze_shortest_paths() {
+    let maze = day16::parse(&example("16.txt"), &Params::new()).unwrap();
+    let (score, states) = maze.shortest_paths(maze.start_state(), maze.end()).unwrap();
+    assert_eq!(score, 7036);
+    assert!(states.iter().any(|state| state.pos == maze.end()));
+}
+
+#[test]
+fn machine_run_program() {
+    let machine = day17::parse(&example("17.txt"), &Params::new()).unwrap();
+    assert_eq!(machine.run_program().unwrap().output(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+}
+
+#[test]
+fn region_sides() {
+    let garden = day12::parse(&example("12.txt"), &Params::new()).unwrap();
+    let sides: Vec<usize> = garden.regions().iter().map(|region| region.sides()).collect();
+    assert_eq!(sides.iter().sum::<usize>(), 4 + 4 + 8 + 4 + 4);
+}
+
+#[test]
+fn robot_after() {
+    let robots = day14::parse(&example("14.txt"), &Params::new().with("height", 7).with("width", 11)).unwrap();
+    for robot in robots.robots() {
+        let mut jumped = robot.clone();
+        for seconds in 0..100 {
+            assert_eq!(robot.after(robots.bounds(), seconds).pos, jumped.pos);
+            jumped = jumped.jump(robots.bounds());
+        }
+    }
+    // The example's robots all cross both sides of the 11x7 room
+    assert_eq!(robots.simulation().brent().run(), Outcome::Cycle(Cycle { start: 0, period: 77 }));
+}
+
+#[test]
+fn answers_above_i64() {
+    let equations = aoc::day07::parse("10000000000000000001: 1 10000000000000000000\n", &Params::new()).unwrap();
+    let answer = aoc::day07::solve_part1(&equations).unwrap();
+    assert_eq!(answer, Answer::Num(10000000000000000001));
+    assert_eq!(serde_json::Value::from(&answer).to_string(), "10000000000000000001");
+}
@@ -0,0 +1,127 @@
+//! Inputs found by fuzzing that used to panic or hang, and must now give an error instead.
+
+use aoc::Params;
+
+fn parse_error(day: u8, input: &str) -> String {
+    match aoc::day(day).unwrap().parse(input, &Params::new()) {
+        Ok(_) => panic!("day {} accepted {:?}", day, input),
+        Err(e) => e.message,
+    }
+}
+
+fn solve_error(day: u8, part: u8, input: &str) -> String {
+    let solution = aoc::day(day).unwrap().parse(input, &Params::new()).unwrap();
+    match solution.part(part) {
+        Ok(answer) => panic!("day {} part {} answered {} for {:?}", day, part, answer, input),
+        Err(e) => e.message,
+    }
+}
+
+#[test]
+fn day01_totals_too_large() {
+    let input = "18446744073709551615   0\n18446744073709551615   0\n";
+    assert_eq!(solve_error(1, 1, input), "the total is too large");
+    let input = "18446744073709551615   18446744073709551615\n18446744073709551615   18446744073709551615\n";
+    assert_eq!(solve_error(1, 2, input), "the total is too large");
+}
+
+#[test]
+fn day05_rules_must_order_every_update() {
+    assert_eq!(parse_error(5, "1|2\n\n1,2,3\n"), "no rule orders pages 1 and 3");
+    assert_eq!(parse_error(5, "1|2\n2|1\n\n1,2\n"), "pages 1 and 2 are ordered both ways");
+    assert_eq!(parse_error(5, "1|2\n2|3\n3|1\n\n1,2,3\n"), "the rules for these pages go round in a circle");
+    assert_eq!(parse_error(5, "1|2\n\n1,1\n"), "page 1 appears twice");
+}
+
+#[test]
+fn day06_guard_in_a_loop() {
+    let input = ".#..\n...#\n#...\n.^#.\n";
+    assert_eq!(solve_error(6, 1, input), "the guard patrols in a loop and never leaves the map");
+}
+
+#[test]
+fn day07_large_numbers() {
+    // Concatenating a 20-digit number used to overflow
+    let solution = aoc::day(7).unwrap().parse("5: 5 10000000000000000000\n", &Params::new()).unwrap();
+    assert_eq!(solution.part(2).unwrap().to_string(), "0");
+    let input = "18446744073709551615: 18446744073709551615\n18446744073709551615: 18446744073709551615\n";
+    assert_eq!(solve_error(7, 1, input), "the total calibration result is too large");
+}
+
+#[test]
+fn day09_empty() {
+    assert_eq!(parse_error(9, ""), "expected a line of digits");
+}
+
+#[test]
+fn day10_not_a_height() {
+    parse_error(10, "0123\n1.34\n");
+}
+
+#[test]
+fn day11_stones_too_large() {
+    assert_eq!(solve_error(11, 1, "9999999999999999999\n"), "the stones grow too large to count");
+}
+
+#[test]
+fn day13_tokens_too_large() {
+    let machine = "Button A: X+1, Y+1\nButton B: X+1, Y+2\nPrize: X=4611686018427387904, Y=4611686018427387904\n";
+    assert_eq!(solve_error(13, 1, &format!("{}\n{}", machine, machine)), "the total number of tokens is too large");
+    // A single machine too costly to count, or a prize moved past what fits,
+    // must not just drop out of the total
+    let machine = "Button A: X+2, Y+1\nButton B: X+1, Y+2\nPrize: X=4, Y=5\n";
+    let solution = aoc::day(13).unwrap().parse(machine, &Params::new().with("b_cost", i64::MAX)).unwrap();
+    assert_eq!(solution.part(1).unwrap_err().message, "the total number of tokens is too large");
+    let machine = "Button A: X+2, Y+1\nButton B: X+1, Y+2\nPrize: X=18446744073709551615, Y=5\n";
+    assert_eq!(solve_error(13, 2, machine), "the total number of tokens is too large");
+}
+
+#[test]
+fn day13_every_machine_must_match() {
+    let machine = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+    let typo = "Button A: X+26, Y=66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176\n";
+    for (input, line, column, text) in [
+        (format!("{}\n{}", machine, typo), 5, 1, "Button A: X+26, Y=66"),
+        (format!("{}\nstray text\n\n{}", machine, machine), 5, 1, "stray text"),
+        (format!("{}extra\n", machine), 4, 1, "extra"),
+        (format!("{}\nButton A: X+1, Y+1\n", machine), 5, 19, ""),
+    ] {
+        let Err(e) = aoc::day(13).unwrap().parse(&input, &Params::new()) else {
+            panic!("day 13 accepted {:?}", input);
+        };
+        assert_eq!((e.line, e.column, e.text.as_str()), (line, column, text), "{}", e);
+    }
+}
+
+#[test]
+fn day14_huge_velocity() {
+    let solution = aoc::day(14).unwrap().parse("p=0,0 v=9223372036854775807,-9223372036854775808\n", &Params::new());
+    let solution = solution.unwrap();
+    solution.part(1).unwrap();
+    solution.part(2).unwrap_err();
+    assert_eq!(parse_error(14, "p=101,0 v=1,1\n"), "the robot is outside the 101x103 room");
+}
+
+#[test]
+fn day15_missing_walls() {
+    parse_error(15, "#####\n#.@O.\n#####\n\n>>\n");
+}
+
+#[test]
+fn day16_no_way_out() {
+    let input = "#####\n#S#E#\n#####\n";
+    assert_eq!(solve_error(16, 1, input), "there is no way from the start to the end");
+}
+
+#[test]
+fn day17_malformed_programs() {
+    let machine = |program: &str| format!("Register A: 5\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", program);
+    assert_eq!(parse_error(17, &machine("0,3,5")), "expected an operand after every opcode");
+    assert_eq!(parse_error(17, &machine("0,7")), "combo operand 7 is reserved");
+    assert_eq!(parse_error(17, &machine("3,1")), "jumps must land on an opcode, not an operand");
+    assert_eq!(solve_error(17, 1, &machine("3,0")), "the program never halts: after 0 instructions it goes round a loop of 1");
+    assert_eq!(
+        solve_error(17, 1, &machine("0,1,1,1,3,2")),
+        "the program never halts: after 1 instructions it goes round a loop of 4",
+    );
+}
@@ -0,0 +1,44 @@
+//! Checks the solvers against the days' original, slow solvers on generated inputs.
+
+mod reference;
+
+use proptest::prelude::*;
+
+use aoc::{Answer, Params};
+
+fn solve(day: u8, part: u8, input: &str) -> Answer {
+    aoc::day(day).unwrap().parse(input, &Params::new()).unwrap().part(part).unwrap()
+}
+
+fn generate(day: u8, seed: u64, size: usize) -> String {
+    aoc::generator(day).unwrap().generate(seed, size)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn day02_dampener(seed: u64, size in 1..50usize) {
+        let input = generate(2, seed, size);
+        prop_assert_eq!(solve(2, 2, &input), reference::day02::part2(&input).into());
+    }
+
+    #[test]
+    fn day07_operators(seed: u64, size in 1..20usize) {
+        let input = generate(7, seed, size);
+        prop_assert_eq!(solve(7, 1, &input), reference::day07::solve_input(&input, false).into());
+        prop_assert_eq!(solve(7, 2, &input), reference::day07::solve_input(&input, true).into());
+    }
+
+    #[test]
+    fn day10_rating(seed: u64, size in 2..30usize) {
+        let input = generate(10, seed, size);
+        prop_assert_eq!(solve(10, 2, &input), reference::day10::part2(&input).into());
+    }
+
+    #[test]
+    fn day16_best_paths(seed: u64, size in 5..25usize) {
+        let input = generate(16, seed, size);
+        prop_assert_eq!(solve(16, 2, &input), reference::day16::part2(&input).into());
+    }
+}
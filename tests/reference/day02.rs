@@ -1,5 +1,3 @@
-use std::io;
-
 fn is_safe(nums: &Vec<i32>) -> bool {
     fn inner(nums: &Vec<i32>) -> bool {
         nums.windows(2).all(|win| 1 <= (win[1] - win[0]) && (win[1] - win[0]) <= 3)
@@ -23,24 +21,16 @@ fn is_safe_with_dampener(nums: &Vec<i32>) -> bool {
     return false;
 }
 
-fn main() {
-    let lines: Vec<Vec<i32>> = io::stdin()
+/// Part 2, as `main` worked it out from stdin.
+pub fn part2(input: &str) -> usize {
+    let lines: Vec<Vec<i32>> = input
         .lines()
-        .map(Result::unwrap)
         .map(|line| line.split(" ").map(|s| s.parse::<i32>().unwrap()).collect())
         .collect();
 
-    let part1: usize = lines
-        .iter()
-        .map(|nums| is_safe(nums))
-        .filter(|b| *b)
-        .count();
-    println!("Part 1: {}", part1);
-
-    let part2: usize = lines
+    lines
         .iter()
         .map(|nums| is_safe_with_dampener(nums))
         .filter(|b| *b)
-        .count();
-    println!("Part 2: {}", part2);
+        .count()
 }
@@ -1,4 +1,3 @@
-use std::io;
 use std::iter::{repeat_n, zip};
 
 use itertools::Itertools;
@@ -70,8 +69,11 @@ fn solve(eqs: &Vec<Equation>, ops: Vec<Op>) -> u64 {
         .sum()
 }
 
-fn main() {
-    let eqs: Vec<Equation> = io::stdin().lines().flatten().map(Equation::parse).collect();
-    println!("Part 1: {}", solve(&eqs, vec![Op::Add, Op::Mul]));
-    println!("Part 2: {}", solve(&eqs, vec![Op::Add, Op::Mul, Op::Cat]));
+/// Part 1, or with `concat` part 2, as `main` worked them out from stdin.
+pub fn solve_input(input: &str, concat: bool) -> u64 {
+    let eqs: Vec<Equation> = input.lines().map(str::to_string).map(Equation::parse).collect();
+    match concat {
+        false => solve(&eqs, vec![Op::Add, Op::Mul]),
+        true => solve(&eqs, vec![Op::Add, Op::Mul, Op::Cat]),
+    }
 }
@@ -1,5 +1,4 @@
 use std::collections::HashSet;
-use std::io;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 struct Pos {
@@ -84,8 +83,8 @@ impl Map {
     }
 }
 
-fn main() {
-    let map = Map::parse(io::stdin().lines().map(Result::unwrap));
-    println!("Part 1: {}", map.trailheads().iter().map(|head| map.score(*head)).sum::<usize>());
-    println!("Part 1: {}", map.trailheads().iter().map(|head| map.rate(*head)).sum::<usize>());
+/// Part 2, as `main` worked it out from stdin.
+pub fn part2(input: &str) -> usize {
+    let map = Map::parse(input.lines().map(str::to_string));
+    map.trailheads().iter().map(|head| map.rate(*head)).sum::<usize>()
 }
@@ -1,7 +1,6 @@
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
-use std::io;
 use std::ops::Add;
 
 use itertools::Itertools;
@@ -165,18 +164,17 @@ impl Maze {
 
 }
 
-fn main() {
-    let maze = Maze::parse(io::stdin().lines().map(Result::unwrap));
+/// Part 2, as `main` worked it out from stdin.
+pub fn part2(input: &str) -> usize {
+    let maze = Maze::parse(input.lines().map(str::to_string));
     let start = State { pos: maze.start, dir: Dir::East };
 
-    let (min_score, min_paths) = maze.shortest_paths(start, maze.end).unwrap();
-    println!("Part 1: {}", min_score);
-    println!("Part 2: {}", min_paths
+    let (_min_score, min_paths) = maze.shortest_paths(start, maze.end).unwrap();
+    min_paths
         .iter()
         .map(|path| path.iter())
         .flatten()
         .map(|state| state.pos)
         .unique()
         .count()
-    );
 }
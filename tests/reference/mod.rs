@@ -0,0 +1,11 @@
+//! The days' first, slow solvers, kept as they were written so that the
+//! faster ones can be checked against them. Only `main` is gone, replaced by a
+//! function that solves a string instead of stdin. Each one parses its own
+//! input, so they share no code with the real solvers.
+
+#![allow(clippy::all, dead_code)]
+
+pub mod day02;
+pub mod day07;
+pub mod day10;
+pub mod day16;
@@ -0,0 +1,102 @@
+//! Checks frames, their images and the animations days provide with `visualize`.
+
+mod common;
+
+use aoc::{Color, Frame, Grid, Palette, Params, Pos, Viewer};
+
+use common::example;
+
+#[test]
+fn frame_overlays() {
+    let grid = Grid::parse(["#.", ".#"], |_, byte| byte == b'#').unwrap();
+    let mut frame = Frame::new(&grid, |_, wall| if *wall { '#' } else { '.' }).with_caption("hi");
+    frame.draw(Pos { y: 0, x: 1 }, '@', Color::Red);
+    frame.highlight(Pos { y: 1, x: 1 }, Color::Gray);
+    frame.draw(Pos { y: 5, x: 5 }, '!', Color::Red);
+    assert_eq!(frame.to_string(), "#@\n.#\nhi\n");
+    assert_eq!(frame.ansi(), "#\x1b[91m@\x1b[0m\n.\x1b[90m#\x1b[0m\nhi\n");
+}
+
+#[test]
+fn text_frame() {
+    let frame = Frame::text("ab\nc\n").with_caption("hi");
+    assert_eq!(frame.cells().size(), Pos { y: 2, x: 2 });
+    assert_eq!(frame.to_string(), "ab\nc \nhi\n");
+}
+
+#[test]
+fn warehouse_frames() {
+    let input = example("15-2.txt");
+    let solution = aoc::day(15).unwrap().parse(&input, &Params::new()).unwrap();
+    let frames: Vec<Frame> = solution.visualize(1).unwrap().collect();
+    // One frame to start with, then one per move
+    assert_eq!(frames.len(), 16);
+    let (map, _) = input.split_once("\n\n").unwrap();
+    assert_eq!(frames[0].to_string(), format!("{}\nInitial state\n", map));
+    assert!(aoc::day(1).unwrap().parse("1   2\n", &Params::new()).unwrap().visualize(1).is_none());
+}
+
+#[test]
+fn ppm_image() {
+    let mut frame = Frame::filled(Pos { y: 1, x: 2 }, '.');
+    frame.draw(Pos { y: 0, x: 1 }, '#', Color::Red);
+    let palette = Palette { colors: &[(Color::Red, [255, 0, 0])], ..Palette::DEFAULT };
+    let image = palette.paint(&frame, 2);
+    assert_eq!((image.width, image.height), (4, 2));
+    let mut ppm = vec![];
+    image.write_ppm(&mut ppm).unwrap();
+    let bg = Palette::DEFAULT.background;
+    let row = [bg, bg, [255, 0, 0], [255, 0, 0]].concat();
+    assert_eq!(ppm, [b"P6\n4 2\n255\n".as_slice(), &row, &row].concat());
+}
+
+#[test]
+fn overlay_frames() {
+    // The last frame of each shows the answer
+    for (day, file, part, end) in [
+        (8, "08.txt", 1, "14 antinodes so far"),
+        (8, "08.txt", 2, "34 antinodes so far"),
+        (12, "12.txt", 1, "total 140"),
+        (12, "12.txt", 2, "total 80"),
+        (17, "17.txt", 1, "Halted"),
+    ] {
+        let solution = aoc::day(day).unwrap().parse(&example(file), &Params::new()).unwrap();
+        let last = solution.visualize(part).unwrap().last().unwrap();
+        assert!(last.caption.ends_with(end), "day {} part {}: {}", day, part, last.caption);
+    }
+    let machine = aoc::day(17).unwrap().parse(&example("17.txt"), &Params::new()).unwrap();
+    let last = machine.visualize(1).unwrap().last().unwrap();
+    assert!(last.to_string().contains("Output:  4,6,3,5,6,3,5,2,1,0\n"), "{}", last);
+}
+
+#[test]
+fn viewer_steps() {
+    // Halves A until it is 0, taking 3 instructions each time
+    let input = "Register A: 1152921504606846976\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+    let solution = aoc::day(17).unwrap().parse(input, &Params::new()).unwrap();
+    let frames: Vec<Frame> = solution.visualize(1).unwrap().collect();
+    assert_eq!(frames.len(), 61 * 3 + 1);
+    let mut viewer = Viewer::new(solution.as_ref(), 1, 10.0).unwrap();
+    assert_eq!((viewer.index(), viewer.current()), (0, Some(&frames[0])));
+    assert_eq!(viewer.frame_count(), None);
+    viewer.forward(3);
+    viewer.back(1);
+    assert_eq!((viewer.index(), viewer.current()), (2, Some(&frames[2])));
+    viewer.forward(1000);
+    assert_eq!(viewer.frame_count(), Some(frames.len()));
+    assert!(viewer.at_end());
+    assert_eq!(viewer.current(), frames.last());
+    // Further back than the viewer remembers, so the frames are made again
+    viewer.goto(1);
+    assert_eq!((viewer.index(), viewer.current()), (1, Some(&frames[1])));
+    viewer.back(5);
+    assert_eq!(viewer.index(), 0);
+
+    viewer.faster();
+    assert_eq!(viewer.fps(), 20.0);
+    for _ in 0..20 {
+        viewer.slower();
+    }
+    assert!(viewer.fps() > 0.0);
+    assert!(Viewer::new(solution.as_ref(), 2, 10.0).is_none());
+}
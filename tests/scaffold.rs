@@ -0,0 +1,49 @@
+//! Checks that `aoc new` registers a day in order and never overwrites one.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+const LIB: &str = "pub mod day01;
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Lists>(1),
+    Day::new::<day03::Memory>(3),
+];
+";
+
+fn scratch_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), LIB).unwrap();
+    fs::write(root.join("answers.txt"), "01 1 2\n").unwrap();
+    root
+}
+
+#[test]
+fn registers_in_order() {
+    let root = scratch_root("order");
+    let written = aoc::scaffold(&root, 2).unwrap();
+    assert!(written.contains(&root.join("src/day02.rs")));
+    assert!(written.contains(&root.join("tests/examples/02.txt")));
+    let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+    assert!(lib.contains("(1),\n    Day::new::<day02::Puzzle>(2),\n    Day::new::<day03::Memory>(3),"));
+    assert_eq!(fs::read_to_string(root.join("answers.txt")).unwrap(), "01 1 2\n02 ? ?\n");
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn refuses_existing_days() {
+    let root = scratch_root("existing");
+    fs::write(root.join("04.input"), "my input\n").unwrap();
+    aoc::scaffold(&root, 4).unwrap();
+    assert_eq!(fs::read_to_string(root.join("04.input")).unwrap(), "my input\n");
+    let module = fs::read_to_string(root.join("src/day04.rs")).unwrap();
+    assert_eq!(aoc::scaffold(&root, 4).unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(aoc::scaffold(&root, 3).unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(fs::read_to_string(root.join("src/day04.rs")).unwrap(), module);
+    fs::remove_dir_all(&root).unwrap();
+}
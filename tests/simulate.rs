@@ -0,0 +1,42 @@
+//! Checks that both kinds of cycle detection find where a simulation starts repeating.
+
+use aoc::{Cycle, Outcome, Simulation};
+use proptest::prelude::*;
+
+/// Where `x -> (x * x + c) % m` first repeats, found the obvious way.
+fn naive_cycle(start: u64, c: u64, m: u64) -> Cycle {
+    let mut seen = vec![start];
+    loop {
+        let next = (seen.last().unwrap() * seen.last().unwrap() + c) % m;
+        if let Some(first) = seen.iter().position(|x| *x == next) {
+            return Cycle { start: first, period: seen.len() - first };
+        }
+        seen.push(next);
+    }
+}
+
+proptest! {
+    #[test]
+    fn finds_cycles(start in 0u64..1000, c in 0u64..1000, m in 1u64..1000) {
+        let step = |x: &u64| Some((x * x + c) % m);
+        let expected = Outcome::Cycle(naive_cycle(start, c, m));
+        prop_assert_eq!(Simulation::new(start, step).run(), expected.clone());
+        prop_assert_eq!(Simulation::new(start, step).brent().run(), expected);
+    }
+}
+
+#[test]
+fn halts() {
+    let countdown = |n: &u32| n.checked_sub(1);
+    assert_eq!(Simulation::new(5, countdown).run(), Outcome::Halted { last: 0, steps: 5 });
+    assert_eq!(Simulation::new(5, countdown).brent().run(), Outcome::Halted { last: 0, steps: 5 });
+    assert_eq!(Simulation::new(5, countdown).limit(3).run(), Outcome::GaveUp { last: 2, steps: 3 });
+    let states: Vec<_> = Simulation::new(3, countdown).states().collect();
+    assert_eq!(states, [Ok(3), Ok(2), Ok(1), Ok(0)]);
+}
+
+#[test]
+fn states_end_at_the_repeat() {
+    let states: Vec<_> = Simulation::new(0, |n: &u32| Some((n + 1) % 3)).states().collect();
+    assert_eq!(states, [Ok(0), Ok(1), Ok(2), Err(Cycle { start: 0, period: 3 })]);
+}
@@ -0,0 +1,119 @@
+//! Checks that traces survive a round trip through their text format, and
+//! that replaying and diffing them behaves.
+
+mod common;
+
+use aoc::{Event, Params, Pos, Replay, Trace};
+
+use common::example;
+
+fn trace(day: u8, part: u8, example_name: &str) -> Trace {
+    let solution = aoc::day(day).unwrap().parse(&example(example_name), &Params::new()).unwrap();
+    let events = solution.trace(part).unwrap().collect();
+    Trace { day, part, events }
+}
+
+fn counting(n: usize) -> Trace {
+    let events = (0..n).map(|i| Event::new("tick").with("n", i)).collect();
+    Trace { day: 1, part: 1, events }
+}
+
+#[test]
+fn round_trip() {
+    let trace = Trace {
+        day: 15,
+        part: 2,
+        events: vec![
+            Event::new("move").with("dir", '<').with_pos("robot", Pos::new(2, 4)).with_positions("pushed", &[]),
+            Event::new("move")
+                .with("dir", 'v')
+                .with_pos("robot", Pos::new(3, 4))
+                .with_positions("pushed", &[Pos::new(4, 4), Pos::new(5, 3)]),
+            Event::new("blocked"),
+        ],
+    };
+    let text = trace.to_string();
+    assert!(text.starts_with("trace day=15 part=2\nmove dir=< robot=4,2 pushed=\n"), "{}", text);
+    assert!(text.contains("pushed=4,4;3,5"), "{}", text);
+    assert_eq!(Trace::parse(&text), Ok(trace));
+}
+
+#[test]
+fn parse_errors() {
+    assert!(Trace::parse("").is_err());
+    assert!(Trace::parse("move dir=<\n").is_err());
+    assert!(Trace::parse("trace day=6\n").is_err());
+    assert!(Trace::parse("trace day=6 part=1\nstep pos\n").is_err());
+    let trace = Trace::parse("# recorded by hand\ntrace day=6 part=1\n\nstep pos=1,2\n").unwrap();
+    assert_eq!(trace.events, [Event::new("step").with("pos", "1,2")]);
+}
+
+#[test]
+fn diff() {
+    let (left, mut right) = (counting(5), counting(5));
+    assert_eq!(left.diff(&right), None);
+    right.events[3] = Event::new("tock");
+    let difference = left.diff(&right).unwrap();
+    assert_eq!(difference.index, 3);
+    assert_eq!(difference.left, Some(Event::new("tick").with("n", 3)));
+    assert_eq!(difference.right, Some(Event::new("tock")));
+
+    let shorter = counting(2);
+    let difference = left.diff(&shorter).unwrap();
+    assert_eq!((difference.index, difference.right), (2, None));
+}
+
+#[test]
+fn replay_steps() {
+    let trace = counting(10);
+    let mut replay = Replay::new(&trace);
+    assert_eq!(replay.current().unwrap().get("n"), Some("0"));
+    replay.forward(3);
+    assert_eq!(replay.index(), 3);
+    replay.back(1);
+    assert_eq!(replay.index(), 2);
+    replay.back(5);
+    assert_eq!(replay.index(), 0);
+    replay.forward(100);
+    assert_eq!(replay.index(), 9);
+    replay.goto(4);
+    assert_eq!(replay.current().unwrap().get("n"), Some("4"));
+    assert!(!replay.next_of_kind("tock"));
+    assert_eq!(replay.index(), 4);
+    assert!(replay.next_of_kind("tick"));
+    assert_eq!(replay.index(), 5);
+
+    let empty = counting(0);
+    let mut replay = Replay::new(&empty);
+    replay.forward(1);
+    assert_eq!(replay.current(), None);
+}
+
+#[test]
+fn guard_trace() {
+    let trace = trace(6, 1, "06.txt");
+    let turns = trace.events.iter().filter(|event| event.kind == "turn").count();
+    assert!(turns > 0);
+    assert!(trace.events.iter().all(|event| event.get("pos").is_some()));
+    let obstructions = self::trace(6, 2, "06.txt");
+    let loops = obstructions.events.iter().filter(|event| event.get("loops") == Some("true")).count();
+    assert_eq!(loops, 6);
+}
+
+#[test]
+fn robot_trace() {
+    let trace = trace(15, 2, "15-2.txt");
+    assert_eq!(trace.events.len(), "<^^>>>vv<v>>v<<".len());
+    assert!(trace.events.iter().any(|event| event.get("pushed").is_some_and(|pushed| !pushed.is_empty())));
+    assert!(trace.events.iter().any(|event| event.kind == "blocked"));
+}
+
+#[test]
+fn vm_trace() {
+    let trace = trace(17, 1, "17.txt");
+    assert!(trace.events.iter().all(|event| event.kind == "exec"));
+    let output: Vec<&str> = trace.events.iter().filter_map(|event| event.get("out")).collect();
+    assert_eq!(output, ["4", "6", "3", "5", "6", "3", "5", "2", "1", "0"]);
+    assert_eq!(trace.events[0].get("op"), Some("adv"));
+    assert!(aoc::day(17).unwrap().parse(&example("17.txt"), &Params::new()).unwrap().trace(2).is_none());
+}
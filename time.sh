@@ -9,5 +9,5 @@ else
   day=$(date +%d)
 fi
 
-cargo build --release --bin "day${day}"
-time ./target/release/"day${day}" < "${day}.input"
+cargo build --release --bin aoc
+./target/release/aoc bench "${day}" $@